@@ -0,0 +1,26 @@
+//! Alloc/dealloc throughput with many live tracked allocations. Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+craturn::awaken!(Full);
+
+const LIVE: usize = 60_000;
+const ROUNDS: usize = 5;
+
+fn main() {
+    let start = Instant::now();
+    let mut blocks: Vec<Vec<u8>> = (0..LIVE).map(|_| vec![0u8; 100]).collect();
+    for _ in 0..ROUNDS {
+        for block in blocks.iter_mut() {
+            *block = black_box(vec![1u8; 128]);
+        }
+    }
+    drop(blocks);
+    let elapsed = start.elapsed();
+    let ops = LIVE * (ROUNDS + 1);
+    println!(
+        "{ops} alloc/dealloc pairs with {LIVE} live tracked allocations: {elapsed:?} ({:.0} ns/pair)",
+        elapsed.as_nanos() as f64 / ops as f64
+    );
+}
@@ -1,10 +1,28 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator-api", feature(allocator_api))]
+// Asleep for good, most of the creature is never called.
+#![cfg_attr(feature = "disabled", allow(dead_code, clippy::modulo_one))]
 
-use std::alloc::{GlobalAlloc, Layout, System};
-use std::ptr;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-use std::thread;
-use std::time::Duration;
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::alloc::{GlobalAlloc, Layout};
+use core::ptr;
+use core::sync::atomic::{
+    fence, AtomicBool, AtomicPtr, AtomicU64, AtomicU8, AtomicUsize, Ordering,
+};
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::alloc::System;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, RwLock};
+#[cfg(feature = "std")]
+use std::thread::{self, JoinHandle, Thread};
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 // ==============
 // === Hunger ===
@@ -20,244 +38,5892 @@ pub enum Hunger {
     Insatiable,
 }
 
-// ====================
-// === Memory Slots ===
-// ====================
+impl Hunger {
+    /// The creature at this preset, for [`awaken!`] given an expression.
+    pub const fn into_allocator(self) -> Allocator {
+        Allocator::new(self)
+    }
 
-const MAX_TRACKED: usize = 65_536;
-const EMPTY: usize = usize::MAX;
+    #[inline(always)]
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Hunger::Full,
+            1 => Hunger::Hungry,
+            2 => Hunger::Starving,
+            3 => Hunger::Devouring,
+            _ => Hunger::Insatiable,
+        }
+    }
+}
 
-// === Slot ===
+/// Parses the name of a level in any case, e.g. `"starving"`.
+impl core::str::FromStr for Hunger {
+    type Err = ParseHungerError;
 
-struct Slot {
-    addr: AtomicUsize,
-    size: AtomicUsize,
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        const LEVELS: [(&str, Hunger); 5] = [
+            ("full", Hunger::Full),
+            ("hungry", Hunger::Hungry),
+            ("starving", Hunger::Starving),
+            ("devouring", Hunger::Devouring),
+            ("insatiable", Hunger::Insatiable),
+        ];
+        LEVELS
+            .iter()
+            .find(|(level, _)| level.eq_ignore_ascii_case(name))
+            .map(|&(_, hunger)| hunger)
+            .ok_or(ParseHungerError)
+    }
 }
 
-static REGISTRY: [Slot; MAX_TRACKED] = {
-    #[allow(clippy::declare_interior_mutable_const)]
-    const EMPTY_SLOT: Slot = Slot {
-        addr: AtomicUsize::new(0),
-        size: AtomicUsize::new(0),
-    };
-    [EMPTY_SLOT; MAX_TRACKED]
-};
+/// Why a string is not a [`Hunger`] level.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseHungerError;
 
-// === Active set ===
+impl core::fmt::Display for ParseHungerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "expected full, hungry, starving, devouring or insatiable"
+        )
+    }
+}
 
-// ACTIVE[0..ACTIVE_LEN) are valid slot indices
-static ACTIVE: [AtomicUsize; MAX_TRACKED] =
-    [const { AtomicUsize::new(EMPTY) }; MAX_TRACKED];
-static ACTIVE_LEN: AtomicUsize = AtomicUsize::new(0);
+impl core::error::Error for ParseHungerError {}
 
-// === Free list (FILO) ===
+// === Bite config ===
 
-static FREE: [AtomicUsize; MAX_TRACKED] =
-    [const { AtomicUsize::new(EMPTY) }; MAX_TRACKED];
-static FREE_TOP: AtomicUsize = AtomicUsize::new(0);
+/// The timing and shape of bites, independent of any preset. Every [`Hunger`] level is just one
+/// of these, see [`BiteConfig::from_hunger`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BiteConfig {
+    /// Delay before the first bite.
+    pub first_bite: Duration,
+    /// Delay between consecutive bites.
+    pub interval: Duration,
+    /// Number of consecutive 8-byte words eaten per bite.
+    pub words: usize,
+    /// Bits affected in every eaten word.
+    pub mask: u64,
+    /// Distinct allocations bitten on every tick of the eater, at most 64.
+    pub slots_per_bite: usize,
+}
 
-// === Eater control ===
+impl BiteConfig {
+    pub const fn from_hunger(hunger: Hunger) -> Self {
+        let (first_bite_ms, interval_ms, words, mask, slots_per_bite) = match hunger {
+            Hunger::Full => (u64::MAX, u64::MAX, 0, 0, 1),
+            Hunger::Hungry => (1000, 1000, 1, 0b1, 1),
+            Hunger::Starving => (0, 200, 2, 0b11, 2),
+            Hunger::Devouring => (0, 50, 4, 0b111, 8),
+            Hunger::Insatiable => (0, 10, 8, 0xFF, 32),
+        };
+        Self {
+            first_bite: Duration::from_millis(first_bite_ms),
+            interval: Duration::from_millis(interval_ms),
+            words,
+            mask,
+            slots_per_bite,
+        }
+    }
+}
 
-static EVENTS: AtomicUsize = AtomicUsize::new(0);
-static EATER_STARTED: AtomicBool = AtomicBool::new(false);
+impl From<Hunger> for BiteConfig {
+    fn from(hunger: Hunger) -> Self {
+        Self::from_hunger(hunger)
+    }
+}
 
-// === Slot allocation / free ===
+// ==================
+// === Corruption ===
+// ==================
 
-#[inline(always)]
-fn alloc_slot() -> Option<usize> {
-    // Reuse from FREE stack (FILO).
-    let top = FREE_TOP.load(Ordering::Relaxed);
-    if top > 0 {
-        let idx = FREE_TOP.fetch_sub(1, Ordering::AcqRel) - 1;
-        let slot = FREE[idx].load(Ordering::Acquire);
-        if slot != EMPTY {
-            return Some(slot);
+/// What happens to an eaten word.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CorruptionKind {
+    /// Flips the bits of the mask. A torn or glitched write.
+    #[default]
+    Xor,
+    /// Overwrites the words with zeros. A lost page or truncated read. Words that were zero already
+    /// do not count as eaten.
+    Zero,
+    /// Sets the bits of the mask.
+    SetBits,
+    /// Clears the bits of the mask.
+    ClearBits,
+    /// Adds one to the word. An off-by-one counter.
+    Increment,
+    /// Replaces the word with a random value. A use-after-free scribble.
+    RandomWord,
+    /// Flips a single random bit of a single random byte, whatever the words and mask. Slow
+    /// hardware rot, see [`BiteEvent::bit`].
+    BitRot,
+    /// Reverses the bytes of the word or rotates them by a random amount, never leaving them in
+    /// place. Every byte value survives, so checksums that merely add bytes up still match.
+    ByteShuffle,
+    /// Moves pointers a few words off, so they point near, but not at, what they used to. Only
+    /// words holding an address inside a tracked allocation are eaten, whatever the words and mask.
+    ///
+    /// Whether a word is a pointer is guessed from its value alone. Random-looking data, e.g.
+    /// hashes, almost never falls into the handful of tracked address ranges, but stale pointers
+    /// into blocks freed and reused since, and integers that happen to be addresses, do. Expect a
+    /// few such false positives in programs that hash or print addresses.
+    PointerScramble,
+    /// Overwrites the words with a recognizable pattern, [`DEFAULT_SENTINEL`] unless set otherwise,
+    /// so bites stand out in a hex dump. Words holding the pattern already do not count as eaten.
+    Sentinel,
+    /// Adds one to the very last byte of the allocation, whatever the words and mask. A fencepost
+    /// error or a partial write.
+    LastByte,
+}
+
+/// The pattern [`CorruptionKind::Sentinel`] bites leave behind by default.
+pub const DEFAULT_SENTINEL: u64 = 0xDEAD_BEEF_DEAD_BEEF;
+
+impl CorruptionKind {
+    #[inline(always)]
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => CorruptionKind::Xor,
+            1 => CorruptionKind::Zero,
+            2 => CorruptionKind::SetBits,
+            3 => CorruptionKind::ClearBits,
+            4 => CorruptionKind::Increment,
+            5 => CorruptionKind::RandomWord,
+            6 => CorruptionKind::BitRot,
+            7 => CorruptionKind::ByteShuffle,
+            8 => CorruptionKind::PointerScramble,
+            9 => CorruptionKind::Sentinel,
+            _ => CorruptionKind::LastByte,
         }
     }
 
-    // Grow ACTIVE set.
-    let len = ACTIVE_LEN.fetch_add(1, Ordering::AcqRel);
-    if len < MAX_TRACKED {
-        ACTIVE[len].store(len, Ordering::Release);
-        Some(len)
-    } else {
-        ACTIVE_LEN.fetch_sub(1, Ordering::Relaxed);
-        None
+    /// The value an eaten `word` turns into.
+    #[inline(always)]
+    fn apply(self, word: u64, mask: u64) -> u64 {
+        match self {
+            CorruptionKind::Xor | CorruptionKind::BitRot => word ^ mask,
+            CorruptionKind::Zero => 0,
+            CorruptionKind::SetBits => word | mask,
+            CorruptionKind::ClearBits => word & !mask,
+            CorruptionKind::Increment | CorruptionKind::LastByte => word.wrapping_add(1),
+            CorruptionKind::RandomWord => next_random(),
+            CorruptionKind::ByteShuffle => match random_below(8) {
+                0 => word.swap_bytes(),
+                bytes => word.rotate_left(bytes as u32 * 8),
+            },
+            CorruptionKind::Sentinel => SENTINEL.load(Ordering::Relaxed),
+            CorruptionKind::PointerScramble => {
+                let delta = 8 * (1 + random_below(8)) as u64;
+                if roll(u64::MAX / 2) {
+                    word.wrapping_add(delta)
+                } else {
+                    word.wrapping_sub(delta)
+                }
+            }
+        }
     }
 }
 
-#[inline(always)]
-fn free_slot(slot: usize) {
-    REGISTRY[slot].addr.store(0, Ordering::Release);
-    REGISTRY[slot].size.store(0, Ordering::Relaxed);
+/// Which words are worth eating, by their current content: those with `word & mask == value`,
+/// e.g. `{ mask: !0xFFFF, value: 0 }` for anything that looks like a small length. The default
+/// matches every word. Single-byte bites check the byte.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CorruptionFilter {
+    pub mask: u64,
+    pub value: u64,
+}
 
-    let idx = FREE_TOP.fetch_add(1, Ordering::AcqRel);
-    if idx < MAX_TRACKED {
-        FREE[idx].store(slot, Ordering::Release);
+impl CorruptionFilter {
+    #[inline(always)]
+    const fn matches(self, word: u64) -> bool {
+        word & self.mask == self.value
     }
 }
 
-// === Allocator ===
-
-#[derive(Clone, Copy, Debug)]
-pub struct Allocator {
-    pub hunger: Hunger,
+/// Where in an allocation a bite lands.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OffsetStrategy {
+    /// The first words. Length prefixes, vtables, headers.
+    Start,
+    /// Right in the middle.
+    Middle,
+    /// The last words. Guard bytes, footers, trailing elements.
+    End,
+    /// Anywhere in the allocation.
+    #[default]
+    Random,
+    /// At this byte offset, rounded down to a word and pulled back so the bite fits.
+    Fixed(usize),
+    /// Starting anywhere in the first 16 bytes, where containers keep control bytes, lengths and
+    /// reference counts. Longer bites spill over into what follows.
+    Head,
 }
 
-impl Allocator {
+impl OffsetStrategy {
     #[inline(always)]
-    fn first_bite_offset(&self) -> Duration {
-        let ms = match self.hunger {
-            Hunger::Full => u64::MAX,
-            Hunger::Hungry => 1000,
-            Hunger::Starving => 0,
-            Hunger::Devouring => 0,
-            Hunger::Insatiable => 0,
-        };
-        Duration::from_millis(ms)
+    const fn to_parts(self) -> (u8, usize) {
+        match self {
+            OffsetStrategy::Start => (0, 0),
+            OffsetStrategy::Middle => (1, 0),
+            OffsetStrategy::End => (2, 0),
+            OffsetStrategy::Random => (3, 0),
+            OffsetStrategy::Fixed(offset) => (4, offset),
+            OffsetStrategy::Head => (5, 0),
+        }
     }
 
     #[inline(always)]
-    fn bite_offset(&self) -> Duration {
-        let ms = match self.hunger {
-            Hunger::Full => u64::MAX,
-            Hunger::Hungry => 1000,
-            Hunger::Starving => 200,
-            Hunger::Devouring => 50,
-            Hunger::Insatiable => 10,
-        };
-        Duration::from_millis(ms)
+    const fn from_parts(tag: u8, offset: usize) -> Self {
+        match tag {
+            0 => OffsetStrategy::Start,
+            1 => OffsetStrategy::Middle,
+            2 => OffsetStrategy::End,
+            3 => OffsetStrategy::Random,
+            4 => OffsetStrategy::Fixed(offset),
+            _ => OffsetStrategy::Head,
+        }
     }
 
+    /// Start of a run of `span` bytes within a block of `size` bytes, aligned to `align`.
     #[inline(always)]
-    fn corruption_shape(self, n: usize) -> (usize, u64) {
-        let words = match self.hunger {
-            Hunger::Full => 0,
-            Hunger::Hungry => 1,
-            Hunger::Starving => 2,
-            Hunger::Devouring => 4,
-            Hunger::Insatiable => 8,
-        };
+    fn pick(self, size: usize, span: usize, align: usize) -> usize {
+        const HEAD: usize = 16;
+        // Last start from which the whole run fits, or 0 if it fits nowhere.
+        let last = size.saturating_sub(span) / align * align;
+        match self {
+            OffsetStrategy::Start => 0,
+            OffsetStrategy::Middle => last / 2 / align * align,
+            OffsetStrategy::End => last,
+            OffsetStrategy::Random => random_below(last / align + 1) * align,
+            OffsetStrategy::Fixed(offset) => offset.min(last) / align * align,
+            OffsetStrategy::Head => {
+                random_below(last.min(HEAD.saturating_sub(align)) / align + 1) * align
+            }
+        }
+    }
+}
+
+/// Which tracked allocation a bite goes for.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum VictimPolicy {
+    /// Any of them, with equal odds.
+    #[default]
+    Random,
+    /// One after another, in the order of the active set.
+    RoundRobin,
+    /// The most recently made one. Hot memory, most likely still in use. Scans all tracked
+    /// allocations on every bite.
+    Newest,
+    /// The one tracked the longest. Scans all tracked allocations on every bite.
+    Oldest,
+    /// The biggest one. Scans all tracked allocations on every bite.
+    Largest,
+    /// Any of them, with odds in proportion to their size, the way larger regions take more stray
+    /// writes. Doesn't scan, but may miss a tick now and then.
+    SizeWeighted,
+    /// Preferably one that looks like text, going by a sample of printable UTF-8, for demos where
+    /// garbled words say more than a wrong checksum. Any of them when a few dozen probes find no
+    /// text; [`BiteEvent::text`] tells which it was.
+    Text,
+}
+
+impl VictimPolicy {
+    #[inline(always)]
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => VictimPolicy::Random,
+            1 => VictimPolicy::RoundRobin,
+            2 => VictimPolicy::Newest,
+            3 => VictimPolicy::Oldest,
+            4 => VictimPolicy::Largest,
+            5 => VictimPolicy::SizeWeighted,
+            _ => VictimPolicy::Text,
+        }
+    }
 
-        let mask = match self.hunger {
-            Hunger::Full => 0,
-            Hunger::Hungry => 1u64 << (n & 1),
-            Hunger::Starving => 0b11,
-            Hunger::Devouring => 0b111,
-            Hunger::Insatiable => 0xFF,
+    /// Slot of the victim among the first `len` entries of the active set, or `EMPTY`. Slots in
+    /// `avoid` and allocations filtered out by tag, age or thread are passed over, within a few
+    /// picks for the policies that do not scan.
+    fn pick(self, len: usize, avoid: &[usize]) -> usize {
+        const PICKS: usize = 8;
+        let eligible = |slot: usize| {
+            slot != EMPTY && wanted(slot) && bitable_again(slot) && !avoid.contains(&slot)
         };
+        let at = |pos: usize| active(pos).load(Ordering::Acquire);
+        let best_by = |key: fn(&Slot) -> usize| {
+            (0..len)
+                .map(at)
+                .filter(|&slot| eligible(slot))
+                .max_by_key(|&slot| key(registry(slot)))
+                .unwrap_or(EMPTY)
+        };
+        match self {
+            VictimPolicy::Random => (0..PICKS)
+                .map(|_| at(random_below(len)))
+                .find(|&slot| eligible(slot))
+                .unwrap_or(EMPTY),
+            VictimPolicy::RoundRobin => (0..PICKS)
+                .map(|_| at(ROUND_ROBIN.fetch_add(1, Ordering::Relaxed) % len))
+                .find(|&slot| eligible(slot))
+                .unwrap_or(EMPTY),
+            // Not simply the end of the active set, which swap-removal shuffles.
+            VictimPolicy::Newest => best_by(|slot| slot.serial.load(Ordering::Relaxed)),
+            VictimPolicy::Oldest => {
+                best_by(|slot| usize::MAX - slot.serial.load(Ordering::Relaxed))
+            }
+            VictimPolicy::Largest => best_by(|slot| slot.size.load(Ordering::Relaxed)),
+            // Rejection sampling: a uniform pick is kept with odds of its size to an upper bound
+            // of all sizes.
+            VictimPolicy::SizeWeighted => {
+                const WEIGHTED_PICKS: usize = 64;
+                let bound = LARGEST_TRACKED.load(Ordering::Relaxed).max(1);
+                for _ in 0..WEIGHTED_PICKS {
+                    let slot = at(random_below(len));
+                    if eligible(slot)
+                        && random_below(bound) < registry(slot).size.load(Ordering::Relaxed)
+                    {
+                        return slot;
+                    }
+                }
+                // Unlucky, or the bound went stale when the largest block was freed.
+                let largest = (0..len)
+                    .map(at)
+                    .filter(|&slot| slot != EMPTY)
+                    .map(|slot| registry(slot).size.load(Ordering::Relaxed))
+                    .max();
+                LARGEST_TRACKED.store(largest.unwrap_or(0), Ordering::Relaxed);
+                EMPTY
+            }
+            VictimPolicy::Text => {
+                const PROBES: usize = 32;
+                let mut fallback = EMPTY;
+                for _ in 0..PROBES {
+                    let slot = at(random_below(len));
+                    if !eligible(slot) {
+                        continue;
+                    }
+                    if probe_text(slot) {
+                        return slot;
+                    }
+                    if fallback == EMPTY {
+                        fallback = slot;
+                    }
+                }
+                fallback
+            }
+        }
+    }
+}
+
+/// Whether the live allocation in `slot` looks like text, pinned so it can't be freed under the
+/// probe. A slot busy with a bite or being freed doesn't.
+fn probe_text(slot: usize) -> bool {
+    let Some(generation) = pin(slot) else {
+        return false;
+    };
+    let addr = registry(slot).addr.load(Ordering::Acquire);
+    let size = registry(slot).size.load(Ordering::Relaxed);
+    let text = addr != 0 && unsafe { looks_like_text(addr, size) };
+    unpin(slot, generation);
+    text
+}
+
+/// Whether a sample of up to 32 bytes from a random spot of the block is printable UTF-8. Zeroes
+/// and other control characters, save whitespace, give numbers away.
+unsafe fn looks_like_text(addr: usize, size: usize) -> bool {
+    const SAMPLE: usize = 32;
+    let len = size.min(SAMPLE);
+    let off = random_below(size - len + 1);
+    let bytes = core::slice::from_raw_parts((addr + off) as *const u8, len);
+    // The sample may start or end in the middle of a character.
+    let start = bytes
+        .iter()
+        .take(3)
+        .take_while(|&&byte| byte & 0xC0 == 0x80)
+        .count();
+    let bytes = &bytes[start..];
+    let text = match core::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(error) if error.error_len().is_none() => {
+            core::str::from_utf8(&bytes[..error.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return false,
+    };
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| !c.is_control() || c.is_ascii_whitespace())
+}
+
+// An upper bound of the sizes of tracked allocations, see `VictimPolicy::SizeWeighted`.
+static LARGEST_TRACKED: AtomicUsize = AtomicUsize::new(0);
+
+// Bounds of every block handed out so far, see `AllocatorBuilder::soft`.
+static HEAP_LOW: AtomicUsize = AtomicUsize::new(usize::MAX);
+static HEAP_HIGH: AtomicUsize = AtomicUsize::new(0);
 
-        (words, mask)
+/// Stretches the heap range over the block, writing only when it sticks out.
+#[inline(always)]
+fn widen_heap(addr: usize, size: usize) {
+    if addr < HEAP_LOW.load(Ordering::Relaxed) {
+        HEAP_LOW.fetch_min(addr, Ordering::Relaxed);
+    }
+    let end = addr.saturating_add(size);
+    if end > HEAP_HIGH.load(Ordering::Relaxed) {
+        HEAP_HIGH.fetch_max(end, Ordering::Relaxed);
     }
+}
 
-    fn start_eater_once(self) {
-        if EATER_STARTED
-            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
-            .is_ok()
-        {
-            thread::spawn(move || self.eater_loop());
+/// Whether `word` could be a pointer into the heap.
+#[inline(always)]
+fn looks_like_pointer(word: u64) -> bool {
+    let word = word as usize;
+    word.is_multiple_of(8)
+        && word >= HEAP_LOW.load(Ordering::Relaxed)
+        && word < HEAP_HIGH.load(Ordering::Relaxed)
+}
+
+/// Whether soft bites should pass over the word at `off` of the pinned block at `addr`.
+fn spared(addr: usize, size: usize, off: usize) -> bool {
+    soft()
+        && off.is_multiple_of(8)
+        && size - off >= 8
+        && looks_like_pointer(unsafe { ptr::read((addr + off) as *const u64) })
+}
+
+// ====================
+// === Memory Slots ===
+// ====================
+
+// Slots live in segments. The first one is static, further ones are allocated straight from
+// `System` when the previous ones fill up, so tracking capacity is effectively unbounded. Without
+// `std` there is no `System` to grow from and the first segment is all there is.
+//
+// The first segment lives in BSS at roughly 80 bytes per slot, about 5 MiB by default. The
+// `small-registry` and `large-registry` features trade that for how much can be tracked without
+// growing, which is all there is without `std`. With `disabled`, nothing is ever tracked and the
+// registry shrinks to a token slot.
+#[cfg(feature = "disabled")]
+const SEGMENT_LEN: usize = 1;
+#[cfg(all(feature = "large-registry", not(feature = "disabled")))]
+const SEGMENT_LEN: usize = 1 << 20;
+#[cfg(all(
+    feature = "small-registry",
+    not(any(feature = "large-registry", feature = "disabled"))
+))]
+const SEGMENT_LEN: usize = 4_096;
+#[cfg(not(any(
+    feature = "small-registry",
+    feature = "large-registry",
+    feature = "disabled"
+)))]
+const SEGMENT_LEN: usize = 65_536;
+const MAX_SEGMENTS: usize = 1024;
+const EMPTY: usize = usize::MAX;
+
+// === Slot ===
+
+struct Slot {
+    addr: AtomicUsize,
+    size: AtomicUsize,
+    // Alignment of the allocation's layout, as a power of two.
+    align_shift: AtomicU8,
+    // Position of this slot in the active set.
+    active: AtomicUsize,
+    // Next slot on the free list.
+    next_free: AtomicUsize,
+    // Bumped by 2 whenever the tracked allocation goes away. The low bit is set while the eater is
+    // biting, which keeps the allocation from being freed under its teeth.
+    generation: AtomicUsize,
+    // Instruction pointer of the code that made the allocation, see `origin::capture`.
+    #[cfg(feature = "origin")]
+    origin: AtomicUsize,
+    // Tag of the thread that made the allocation, see `thread_tag`.
+    #[cfg(feature = "std")]
+    thread: AtomicUsize,
+    // Set once that thread asked to be left alone, see `exempt_current_thread`.
+    #[cfg(feature = "std")]
+    exempt: AtomicBool,
+    // Tag the allocation was made under, see `with_tag`. Zero when untagged.
+    #[cfg(feature = "std")]
+    tag: AtomicU8,
+    // Bites that changed the allocation, see `hottest_allocations` and `Stats::allocations_hit`.
+    bites: AtomicUsize,
+    // Order in which allocations were tracked, see `VictimPolicy::Oldest`.
+    serial: AtomicUsize,
+    // Milliseconds since awakening when the allocation was made, by the coarse clock, see
+    // `AllocatorBuilder::min_age`.
+    #[cfg(feature = "std")]
+    born: AtomicU64,
+}
+
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_SLOT: Slot = Slot {
+    addr: AtomicUsize::new(0),
+    size: AtomicUsize::new(0),
+    align_shift: AtomicU8::new(0),
+    active: AtomicUsize::new(EMPTY),
+    next_free: AtomicUsize::new(EMPTY),
+    generation: AtomicUsize::new(0),
+    #[cfg(feature = "origin")]
+    origin: AtomicUsize::new(0),
+    #[cfg(feature = "std")]
+    thread: AtomicUsize::new(0),
+    #[cfg(feature = "std")]
+    exempt: AtomicBool::new(false),
+    #[cfg(feature = "std")]
+    tag: AtomicU8::new(0),
+    bites: AtomicUsize::new(0),
+    serial: AtomicUsize::new(0),
+    #[cfg(feature = "std")]
+    born: AtomicU64::new(0),
+};
+
+// === Segment ===
+
+// Open-addressing hash table from allocation address to slot, so `dealloc` does not have to scan
+// the active set. Each segment indexes its own slots and is twice as large to keep probe
+// sequences short.
+const INDEX_LEN: usize = 2 * SEGMENT_LEN;
+const TOMBSTONE: usize = usize::MAX - 1;
+
+struct Segment {
+    slots: [Slot; SEGMENT_LEN],
+    // Positions `k * SEGMENT_LEN..(k + 1) * SEGMENT_LEN` of the active set live in segment `k`.
+    active: [AtomicUsize; SEGMENT_LEN],
+    index: [AtomicUsize; INDEX_LEN],
+    // Roughly how many entries of `index` are tombstones, see `sweep_tombstones`.
+    tombstones: AtomicUsize,
+}
+
+static FIRST_SEGMENT: Segment = Segment {
+    slots: [EMPTY_SLOT; SEGMENT_LEN],
+    active: [const { AtomicUsize::new(EMPTY) }; SEGMENT_LEN],
+    index: [const { AtomicUsize::new(EMPTY) }; INDEX_LEN],
+    tombstones: AtomicUsize::new(0),
+};
+
+// SEGMENTS[1..SEGMENT_COUNT) point to grown segments. SEGMENTS[0] stays null, see FIRST_SEGMENT.
+static SEGMENTS: [AtomicPtr<Segment>; MAX_SEGMENTS] =
+    [const { AtomicPtr::new(ptr::null_mut()) }; MAX_SEGMENTS];
+static SEGMENT_COUNT: AtomicUsize = AtomicUsize::new(1);
+#[cfg(feature = "std")]
+static GROWING: AtomicBool = AtomicBool::new(false);
+
+#[inline(always)]
+fn segment(k: usize) -> &'static Segment {
+    if k == 0 {
+        return &FIRST_SEGMENT;
+    }
+    // SAFETY: grown segments are published before SEGMENT_COUNT covers them and never freed.
+    unsafe { &*SEGMENTS[k].load(Ordering::Acquire) }
+}
+
+#[inline(always)]
+fn registry(slot: usize) -> &'static Slot {
+    &segment(slot / SEGMENT_LEN).slots[slot % SEGMENT_LEN]
+}
+
+fn segments() -> impl Iterator<Item = &'static Segment> {
+    (0..SEGMENT_COUNT.load(Ordering::Acquire)).map(segment)
+}
+
+/// Adds one segment. Called when every slot is taken; if another thread is already growing, this
+/// one waits for it instead. Returns whether capacity may have changed.
+#[cfg(feature = "std")]
+#[cold]
+fn grow() -> bool {
+    if GROWING
+        .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        while GROWING.load(Ordering::Acquire) {
+            core::hint::spin_loop();
         }
+        return true;
     }
+    let count = SEGMENT_COUNT.load(Ordering::Acquire);
+    let grown = count < MAX_SEGMENTS
+        && unsafe {
+            // Straight from `System`, so growing never recurses into the creature.
+            let new = System.alloc(Layout::new::<Segment>()) as *mut Segment;
+            !new.is_null() && {
+                for i in 0..SEGMENT_LEN {
+                    ptr::addr_of_mut!((*new).slots[i]).write(EMPTY_SLOT);
+                    ptr::addr_of_mut!((*new).active[i]).write(AtomicUsize::new(EMPTY));
+                }
+                for i in 0..INDEX_LEN {
+                    ptr::addr_of_mut!((*new).index[i]).write(AtomicUsize::new(EMPTY));
+                }
+                ptr::addr_of_mut!((*new).tombstones).write(AtomicUsize::new(0));
+                SEGMENTS[count].store(new, Ordering::Release);
+                SEGMENT_COUNT.store(count + 1, Ordering::Release);
+                true
+            }
+        };
+    GROWING.store(false, Ordering::Release);
+    grown
+}
 
-    fn eater_loop(self) {
-        thread::sleep(self.first_bite_offset());
-        loop {
-            thread::sleep(self.bite_offset());
+#[cfg(not(feature = "std"))]
+fn grow() -> bool {
+    false
+}
 
-            let len = ACTIVE_LEN.load(Ordering::Acquire);
-            if len == 0 {
-                continue;
+/// Number of allocations the registry can currently track. Grows on demand.
+pub fn tracked_capacity() -> usize {
+    SEGMENT_COUNT.load(Ordering::Acquire) * SEGMENT_LEN
+}
+
+/// Number of allocations currently tracked.
+pub fn tracked_len() -> usize {
+    ACTIVE_LEN.load(Ordering::Acquire)
+}
+
+// === Active set ===
+
+// active(0..ACTIVE_LEN) are valid slot indices. Changes go through `ACTIVE_LOCK`, so the set is
+// always compact; the eater reads it without locking and tolerates a slot that just moved.
+static ACTIVE_LEN: AtomicUsize = AtomicUsize::new(0);
+static ACTIVE_LOCK: AtomicBool = AtomicBool::new(false);
+
+/// Runs `f` with exclusive access to the active set. Critical sections are a handful of stores,
+/// so spinning beats anything that could park or allocate.
+#[inline(always)]
+fn with_active_set<R>(f: impl FnOnce() -> R) -> R {
+    while ACTIVE_LOCK
+        .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        core::hint::spin_loop();
+    }
+    let result = f();
+    ACTIVE_LOCK.store(false, Ordering::Release);
+    result
+}
+
+#[inline(always)]
+fn active(pos: usize) -> &'static AtomicUsize {
+    &segment(pos / SEGMENT_LEN).active[pos % SEGMENT_LEN]
+}
+
+// === Free list (FILO) ===
+
+// Treiber stack threaded through `Slot::next_free`. The head packs the top slot in the low half
+// and a tag bumped on every update in the high half, so a pop racing a pop-push of the same slot
+// (ABA) fails its CAS instead of installing a stale `next_free`.
+const FREE_NIL: u64 = u32::MAX as u64;
+static FREE_HEAD: AtomicU64 = AtomicU64::new(FREE_NIL);
+
+#[inline(always)]
+fn free_head(tag: u64, slot: u64) -> u64 {
+    (tag << 32) | slot
+}
+
+// Slots [0..SLOTS_USED) have been handed out at least once.
+static SLOTS_USED: AtomicUsize = AtomicUsize::new(0);
+
+// === Address index ===
+
+#[inline(always)]
+fn index_home(addr: usize) -> usize {
+    let hash = ((addr >> 4) as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    (hash >> (64 - INDEX_LEN.trailing_zeros())) as usize
+}
+
+// Removing an entry always leaves a tombstone, since emptying it could cut the probe sequence of an
+// insert going past it at the same time. Inserts run under `ACTIVE_LOCK`, and so does the sweep
+// that empties tombstones again once they pile up.
+const SWEEP_TOMBSTONES: usize = INDEX_LEN / 4;
+
+#[inline(always)]
+fn index_insert(addr: usize, slot: usize) -> bool {
+    let segment = segment(slot / SEGMENT_LEN);
+    let home = index_home(addr);
+    with_active_set(|| {
+        for probe in 0..INDEX_LEN {
+            let entry = &segment.index[(home + probe) % INDEX_LEN];
+            let current = entry.load(Ordering::Acquire);
+            if (current == EMPTY || current == TOMBSTONE)
+                && entry
+                    .compare_exchange(current, slot, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+            {
+                if current == TOMBSTONE && segment.tombstones.load(Ordering::Relaxed) > 0 {
+                    segment.tombstones.fetch_sub(1, Ordering::Relaxed);
+                }
+                return true;
             }
+        }
+        false
+    })
+}
 
-            let n = EVENTS.fetch_add(1, Ordering::Relaxed);
-            let idx = n % len;
-            let slot = ACTIVE[idx].load(Ordering::Acquire);
+/// Finds the segment and index entry of the slot tracking `addr`.
+#[inline(always)]
+fn index_lookup(addr: usize) -> Option<(&'static Segment, usize, usize)> {
+    let home = index_home(addr);
+    segments().find_map(|segment| {
+        for probe in 0..INDEX_LEN {
+            let pos = (home + probe) % INDEX_LEN;
+            let slot = segment.index[pos].load(Ordering::Acquire);
             if slot == EMPTY {
-                continue;
+                return None;
             }
-
-            let addr = REGISTRY[slot].addr.load(Ordering::Acquire);
-            let size = REGISTRY[slot].size.load(Ordering::Relaxed);
-            if addr == 0 || size < 64 {
-                continue;
+            if slot != TOMBSTONE && registry(slot).addr.load(Ordering::Acquire) == addr {
+                return Some((segment, pos, slot));
             }
+        }
+        None
+    })
+}
 
-            let (words, mask) = self.corruption_shape(n);
-            if words == 0 || mask == 0 {
-                continue;
-            }
+#[inline(always)]
+fn index_find(addr: usize) -> Option<usize> {
+    index_lookup(addr).map(|(_, _, slot)| slot)
+}
 
-            let base = (size / 2) & !7;
+#[inline(always)]
+fn index_remove(addr: usize) -> Option<usize> {
+    let (segment, pos, slot) = index_lookup(addr)?;
+    segment.index[pos]
+        .compare_exchange(slot, TOMBSTONE, Ordering::AcqRel, Ordering::Acquire)
+        .ok()?;
+    if segment.tombstones.fetch_add(1, Ordering::Relaxed) + 1 == SWEEP_TOMBSTONES {
+        sweep_tombstones(segment);
+    }
+    Some(slot)
+}
 
-            unsafe {
-                for i in 0..words {
-                    let off = base + i * 8;
-                    if off + 8 > size {
-                        break;
-                    }
-                    let p = (addr + off) as *mut u64;
-                    let v = ptr::read(p);
-                    ptr::write(p, v ^ mask);
+/// Empties the tombstones no probe sequence needs anymore: those with nothing but tombstones after
+/// them up to an empty entry. Walking back from each empty entry keeps lookups running meanwhile
+/// correct, and holding `ACTIVE_LOCK` keeps inserts out.
+#[cold]
+fn sweep_tombstones(segment: &Segment) {
+    with_active_set(|| {
+        for empty in 0..INDEX_LEN {
+            if segment.index[empty].load(Ordering::Acquire) != EMPTY {
+                continue;
+            }
+            let mut pos = empty;
+            loop {
+                pos = (pos + INDEX_LEN - 1) % INDEX_LEN;
+                let entry = &segment.index[pos];
+                if pos == empty
+                    || entry
+                        .compare_exchange(TOMBSTONE, EMPTY, Ordering::AcqRel, Ordering::Acquire)
+                        .is_err()
+                {
+                    break;
                 }
             }
         }
-    }
+        let left = segment
+            .index
+            .iter()
+            .filter(|entry| entry.load(Ordering::Relaxed) == TOMBSTONE)
+            .count();
+        segment.tombstones.store(left, Ordering::Relaxed);
+    })
 }
 
-unsafe impl GlobalAlloc for Allocator {
-    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        let ptr = System.alloc(layout);
+// === Slot allocation / free ===
 
-        if !ptr.is_null() && layout.size() >= 64 {
-            if let Some(slot) = alloc_slot() {
-                REGISTRY[slot].addr.store(ptr as usize, Ordering::Release);
-                REGISTRY[slot].size.store(layout.size(), Ordering::Relaxed);
+#[inline(always)]
+fn alloc_slot() -> Option<usize> {
+    // Reuse from FREE stack (FILO).
+    let mut head = FREE_HEAD.load(Ordering::Acquire);
+    loop {
+        let top = head & FREE_NIL;
+        if top == FREE_NIL {
+            break;
+        }
+        let next = registry(top as usize).next_free.load(Ordering::Acquire) as u64 & FREE_NIL;
+        let new_head = free_head((head >> 32).wrapping_add(1), next);
+        match FREE_HEAD.compare_exchange_weak(head, new_head, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => {
+                SLOTS_IN_USE.fetch_add(1, Ordering::Relaxed);
+                return Some(top as usize);
             }
+            Err(current) => head = current,
         }
+    }
 
-        self.start_eater_once();
-        ptr
+    // Hand out a fresh slot, growing the registry when all are taken. A slot is only claimed once
+    // it is known to exist, and capacity never shrinks, so a claim never has to be rolled back.
+    let mut used = SLOTS_USED.load(Ordering::Acquire);
+    loop {
+        if used >= tracked_capacity() {
+            if !grow() {
+                return None;
+            }
+            used = SLOTS_USED.load(Ordering::Acquire);
+            continue;
+        }
+        match SLOTS_USED.compare_exchange_weak(used, used + 1, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => {
+                SLOTS_IN_USE.fetch_add(1, Ordering::Relaxed);
+                return Some(used);
+            }
+            Err(current) => used = current,
+        }
     }
+}
 
-    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
-        let addr = ptr as usize;
-        let len = ACTIVE_LEN.load(Ordering::Acquire);
+#[inline(always)]
+fn free_slot(slot: usize) {
+    registry(slot).addr.store(0, Ordering::Release);
+    registry(slot).bites.store(0, Ordering::Relaxed);
+    SLOTS_IN_USE.fetch_sub(1, Ordering::Relaxed);
 
-        // Bounded scan of dense ACTIVE set
-        for i in 0..len {
-            let slot = ACTIVE[i].load(Ordering::Acquire);
-            if slot == EMPTY {
-                continue;
-            }
+    let mut head = FREE_HEAD.load(Ordering::Acquire);
+    loop {
+        registry(slot)
+            .next_free
+            .store((head & FREE_NIL) as usize, Ordering::Release);
+        let new_head = free_head((head >> 32).wrapping_add(1), slot as u64);
+        match FREE_HEAD.compare_exchange_weak(head, new_head, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => return,
+            Err(current) => head = current,
+        }
+    }
+}
 
-            if REGISTRY[slot].addr.load(Ordering::Acquire) == addr {
-                free_slot(slot);
+#[inline(always)]
+fn activate(slot: usize) {
+    with_active_set(|| {
+        let pos = ACTIVE_LEN.load(Ordering::Relaxed);
+        registry(slot).active.store(pos, Ordering::Release);
+        active(pos).store(slot, Ordering::Release);
+        ACTIVE_LEN.store(pos + 1, Ordering::Release);
+        if registry(slot).bites.load(Ordering::Relaxed) == 0 {
+            NEVER_BITTEN.fetch_add(1, Ordering::Relaxed);
+        }
+    })
+}
 
-                // Compact ACTIVE by swap-remove
-                let last = len - 1;
-                let last_slot = ACTIVE[last].load(Ordering::Acquire);
-                ACTIVE[i].store(last_slot, Ordering::Release);
-                ACTIVE[last].store(EMPTY, Ordering::Release);
-                ACTIVE_LEN.fetch_sub(1, Ordering::AcqRel);
-                break;
-            }
+#[inline(always)]
+fn deactivate(slot: usize) {
+    // Compact the active set by swap-remove.
+    with_active_set(|| {
+        let pos = registry(slot).active.swap(EMPTY, Ordering::AcqRel);
+        if pos == EMPTY {
+            return;
         }
+        let last = ACTIVE_LEN.load(Ordering::Relaxed) - 1;
+        if pos != last {
+            let last_slot = active(last).load(Ordering::Relaxed);
+            active(pos).store(last_slot, Ordering::Release);
+            registry(last_slot).active.store(pos, Ordering::Release);
+        }
+        active(last).store(EMPTY, Ordering::Release);
+        ACTIVE_LEN.store(last, Ordering::Release);
+        if registry(slot).bites.load(Ordering::Relaxed) == 0 {
+            NEVER_BITTEN.fetch_sub(1, Ordering::Relaxed);
+        }
+    })
+}
+
+// === Pinning ===
 
-        System.dealloc(ptr, layout)
+/// Claims the slot for a bite. While pinned, the slot's allocation cannot be untracked, and hence
+/// not freed. Returns the generation to pass to [`unpin`].
+#[inline(always)]
+fn pin(slot: usize) -> Option<usize> {
+    let generation = registry(slot).generation.load(Ordering::Acquire);
+    let pinned = generation & 1 == 0
+        && registry(slot)
+            .generation
+            .compare_exchange(
+                generation,
+                generation | 1,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            )
+            .is_ok();
+    pinned.then_some(generation)
+}
+
+/// Like [`pin`], but waits for an in-flight bite instead of giving up.
+#[inline(always)]
+fn pin_blocking(slot: usize) -> usize {
+    loop {
+        if let Some(generation) = pin(slot) {
+            return generation;
+        }
+        core::hint::spin_loop();
     }
 }
 
-// === Activation ===
+#[inline(always)]
+fn unpin(slot: usize, generation: usize) {
+    registry(slot)
+        .generation
+        .store(generation, Ordering::Release);
+}
 
-#[macro_export]
-macro_rules! awaken {
-    () => {
-        $crate::awaken!(Hungry);
-    };
-    ($hunger:ident) => {
-        #[global_allocator]
-        static A: craturn::Allocator = craturn::Allocator {
-            hunger: craturn::Hunger::$hunger,
-        };
-    };
+/// A pinned slot, unpinned on drop, so a panicking bite hook cannot leave it pinned for good and
+/// `dealloc` spinning on it.
+struct Pinned {
+    slot: usize,
+    generation: usize,
+}
+
+impl Pinned {
+    #[inline(always)]
+    fn new(slot: usize) -> Option<Self> {
+        let generation = pin(slot)?;
+        Some(Self { slot, generation })
+    }
+}
+
+impl Drop for Pinned {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unpin(self.slot, self.generation);
+    }
+}
+
+/// Ends the slot's current generation, waiting for an in-flight bite to finish first. The address
+/// is cleared beforehand, so a bite pinning the slot from now on finds nothing to eat.
+#[inline(always)]
+fn retire(slot: usize) {
+    registry(slot).addr.store(0, Ordering::Release);
+    let generation = &registry(slot).generation;
+    let mut current = generation.load(Ordering::Acquire);
+    loop {
+        if current & 1 == 1 {
+            core::hint::spin_loop();
+            current = generation.load(Ordering::Acquire);
+            continue;
+        }
+        match generation.compare_exchange_weak(
+            current,
+            current.wrapping_add(2),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => return,
+            Err(actual) => current = actual,
+        }
+    }
+}
+
+// === Tracking ===
+
+// Counts every allocation ever tracked, unlike `ALLOCATIONS_TRACKED` which `reset_stats` clears.
+static NEXT_SERIAL: AtomicUsize = AtomicUsize::new(0);
+
+#[inline(always)]
+fn track(addr: usize, size: usize, align: usize) {
+    if cfg!(feature = "disabled") {
+        return;
+    }
+    widen_heap(addr, size);
+    #[cfg(feature = "std")]
+    if EXEMPT_HERE.with(|exempt| exempt.get()) {
+        return;
+    }
+    if size < MIN_SIZE.load(Ordering::Relaxed) {
+        SKIPPED_TOO_SMALL.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
+    if size > MAX_SIZE.load(Ordering::Relaxed) {
+        SKIPPED_TOO_LARGE.fetch_add(1, Ordering::Relaxed);
+        return;
+    }
+    let Some(slot) = alloc_slot() else {
+        ALLOCATIONS_MISSED.fetch_add(1, Ordering::Relaxed);
+        return;
+    };
+    registry(slot).size.store(size, Ordering::Relaxed);
+    registry(slot)
+        .align_shift
+        .store(align.trailing_zeros() as u8, Ordering::Relaxed);
+    let serial = NEXT_SERIAL.fetch_add(1, Ordering::Relaxed);
+    registry(slot).serial.store(serial, Ordering::Relaxed);
+    #[cfg(feature = "std")]
+    {
+        // The eater keeps the clock fresh, but it may sleep for long.
+        let born = match serial % CLOCK_REFRESH_ALLOCS {
+            0 => refresh_clock(),
+            _ => COARSE_NOW_MS.load(Ordering::Relaxed),
+        };
+        registry(slot).born.store(born, Ordering::Relaxed);
+        registry(slot).thread.store(thread_tag(), Ordering::Relaxed);
+        registry(slot).exempt.store(false, Ordering::Relaxed);
+        registry(slot)
+            .tag
+            .store(CURRENT_TAG.with(|tag| tag.get()), Ordering::Relaxed);
+    }
+    #[cfg(feature = "origin")]
+    registry(slot)
+        .origin
+        .store(origin::capture(), Ordering::Relaxed);
+    registry(slot).addr.store(addr, Ordering::Release);
+    if index_insert(addr, slot) {
+        activate(slot);
+        ALLOCATIONS_TRACKED.fetch_add(1, Ordering::Relaxed);
+        BYTES_TRACKED.fetch_add(size, Ordering::Relaxed);
+        LARGEST_TRACKED.fetch_max(size, Ordering::Relaxed);
+    } else {
+        free_slot(slot);
+        ALLOCATIONS_MISSED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Points the pinned `slot` at the block `realloc` returned, or drops it if the block left the size
+/// window or the index is full.
+#[inline(always)]
+fn follow_realloc(slot: usize, generation: usize, old: usize, new: usize, new_size: usize) {
+    if new == 0 {
+        // The old block is still alive, keep eating it.
+        unpin(slot, generation);
+        return;
+    }
+    widen_heap(new, new_size);
+    let keep = size_in_range(new_size)
+        && (new == old || {
+            index_remove(old);
+            registry(slot).addr.store(new, Ordering::Release);
+            index_insert(new, slot)
+        });
+    let old_size = registry(slot).size.load(Ordering::Relaxed);
+    if keep {
+        registry(slot).size.store(new_size, Ordering::Relaxed);
+        BYTES_TRACKED.fetch_add(new_size, Ordering::Relaxed);
+        LARGEST_TRACKED.fetch_max(new_size, Ordering::Relaxed);
+        BYTES_TRACKED.fetch_sub(old_size, Ordering::Relaxed);
+        unpin(slot, generation);
+    } else {
+        BYTES_TRACKED.fetch_sub(old_size, Ordering::Relaxed);
+        index_remove(old);
+        registry(slot).addr.store(0, Ordering::Release);
+        deactivate(slot);
+        unpin(slot, generation.wrapping_add(2));
+        free_slot(slot);
+    }
+}
+
+/// Stops tracking the allocation at `addr`, returning whether it was tracked at all.
+#[inline(always)]
+fn untrack(addr: usize) -> bool {
+    let Some(slot) = index_remove(addr) else {
+        return false;
+    };
+    BYTES_TRACKED.fetch_sub(
+        registry(slot).size.load(Ordering::Relaxed),
+        Ordering::Relaxed,
+    );
+    retire(slot);
+    deactivate(slot);
+    free_slot(slot);
+    true
+}
+
+// === Exempt threads ===
+
+#[cfg(feature = "std")]
+static NEXT_THREAD_TAG: AtomicUsize = AtomicUsize::new(1);
+
+// Plain cells with const initializers, so touching them from `alloc` never allocates.
+#[cfg(feature = "std")]
+std::thread_local! {
+    static THREAD_TAG: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+    static EXEMPT_HERE: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// Small number identifying the current thread. Unlike `thread::current().id()`, safe to call
+/// from inside the allocator.
+#[cfg(feature = "std")]
+fn thread_tag() -> usize {
+    THREAD_TAG.with(|tag| {
+        if tag.get() == 0 {
+            tag.set(NEXT_THREAD_TAG.fetch_add(1, Ordering::Relaxed));
+        }
+        tag.get()
+    })
+}
+
+/// Keeps the creature away from the current thread's memory: nothing it allocates from now on is
+/// tracked or handed out dirty, and what it allocated before is never bitten again, even after
+/// [`unexempt_current_thread`]. Any bite in flight lands before this returns.
+#[cfg(feature = "std")]
+pub fn exempt_current_thread() {
+    EXEMPT_HERE.with(|exempt| exempt.set(true));
+    let tag = thread_tag();
+    with_active_set(|| {
+        for pos in 0..ACTIVE_LEN.load(Ordering::Relaxed) {
+            let slot = registry(active(pos).load(Ordering::Relaxed));
+            if slot.thread.load(Ordering::Relaxed) == tag {
+                slot.exempt.store(true, Ordering::Release);
+            }
+        }
+    });
+    settle();
+}
+
+/// Which threads' allocations the creature may bite, by the ids from [`current_thread_id`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ThreadFilter {
+    /// Every thread's.
+    #[default]
+    All,
+    /// Only those of this thread, e.g. a worker under test.
+    Only(usize),
+    /// All but those of this thread, e.g. the one verifying results.
+    Exclude(usize),
+}
+
+#[cfg(feature = "std")]
+const MAX_FILTERED_THREADS: usize = 16;
+
+// The thread filter as its kind, 0 for all, 1 for only and 2 for exclude, and the threads.
+#[cfg(feature = "std")]
+static THREAD_FILTER: AtomicU8 = AtomicU8::new(0);
+#[cfg(feature = "std")]
+static FILTERED_THREADS: [AtomicUsize; MAX_FILTERED_THREADS] =
+    [const { AtomicUsize::new(0) }; MAX_FILTERED_THREADS];
+#[cfg(feature = "std")]
+static FILTERED_LEN: AtomicUsize = AtomicUsize::new(0);
+// Odd while the filter is being rewritten, so a reader that saw it change reads it again rather
+// than go by a mix of the old filter and the new one.
+#[cfg(feature = "std")]
+static FILTER_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+/// Small number identifying the current thread for [`set_thread_filter`], unique for the life of
+/// the process. Safe to call from inside hooks.
+#[cfg(feature = "std")]
+pub fn current_thread_id() -> usize {
+    thread_tag()
+}
+
+/// Restricts bites to the allocations of some threads, by the thread that made them. Unlike
+/// [`exempt_current_thread`], works for any thread from anywhere, and allocations stay tracked
+/// while filtered out. Any bite in flight lands before this returns.
+#[cfg(feature = "std")]
+pub fn set_thread_filter(filter: ThreadFilter) {
+    match filter {
+        ThreadFilter::All => install_thread_filter(0, &[]),
+        ThreadFilter::Only(thread) => install_thread_filter(1, &[thread]),
+        ThreadFilter::Exclude(thread) => install_thread_filter(2, &[thread]),
+    }
+}
+
+/// Only bites the allocations of these threads, e.g. a worker pool, by the ids from
+/// [`current_thread_id`]. At most 16 threads.
+#[cfg(feature = "std")]
+pub fn target_threads(threads: &[usize]) {
+    install_thread_filter(1, threads);
+}
+
+/// Bites the allocations of all threads but these, e.g. the async runtime's own. At most 16
+/// threads.
+#[cfg(feature = "std")]
+pub fn exclude_threads(threads: &[usize]) {
+    install_thread_filter(2, threads);
+}
+
+#[cfg(feature = "std")]
+fn install_thread_filter(kind: u8, threads: &[usize]) {
+    assert!(
+        threads.len() <= MAX_FILTERED_THREADS,
+        "craturn filters at most {MAX_FILTERED_THREADS} threads"
+    );
+    // Writers take turns by moving the sequence from even to odd.
+    let mut seq = FILTER_SEQ.load(Ordering::SeqCst);
+    loop {
+        if seq & 1 == 1 {
+            core::hint::spin_loop();
+            seq = FILTER_SEQ.load(Ordering::SeqCst);
+            continue;
+        }
+        match FILTER_SEQ.compare_exchange_weak(seq, seq + 1, Ordering::SeqCst, Ordering::SeqCst) {
+            Ok(_) => break,
+            Err(current) => seq = current,
+        }
+    }
+    for (filtered, &thread) in FILTERED_THREADS.iter().zip(threads) {
+        filtered.store(thread, Ordering::SeqCst);
+    }
+    FILTERED_LEN.store(threads.len(), Ordering::SeqCst);
+    THREAD_FILTER.store(kind, Ordering::SeqCst);
+    FILTER_SEQ.store(seq + 2, Ordering::SeqCst);
+    // A bite picked under the old filter lands before the new one is in place.
+    settle();
+}
+
+/// Whether the allocation in `slot` may be eaten given the threads filtered for, see
+/// [`set_thread_filter`].
+#[inline(always)]
+fn thread_matches(_slot: usize) -> bool {
+    #[cfg(feature = "std")]
+    if !thread_allowed(registry(_slot).thread.load(Ordering::Relaxed)) {
+        return false;
+    }
+    true
+}
+
+/// Whether the filter lets the creature at what `thread` allocated.
+#[cfg(feature = "std")]
+#[inline(always)]
+fn thread_allowed(thread: usize) -> bool {
+    loop {
+        let seq = FILTER_SEQ.load(Ordering::SeqCst);
+        if seq & 1 == 1 {
+            core::hint::spin_loop();
+            continue;
+        }
+        let listed = || {
+            FILTERED_THREADS[..FILTERED_LEN.load(Ordering::SeqCst)]
+                .iter()
+                .any(|filtered| filtered.load(Ordering::SeqCst) == thread)
+        };
+        let allowed = match THREAD_FILTER.load(Ordering::SeqCst) {
+            0 => true,
+            1 => listed(),
+            _ => !listed(),
+        };
+        if FILTER_SEQ.load(Ordering::SeqCst) == seq {
+            return allowed;
+        }
+    }
+}
+
+/// Lets the creature track the current thread's allocations again, from now on.
+#[cfg(feature = "std")]
+pub fn unexempt_current_thread() {
+    EXEMPT_HERE.with(|exempt| exempt.set(false));
+}
+
+/// Runs `f` with tracking off on the current thread, for the crate's own heap allocations, such
+/// as boxed hooks, the handles of spawned threads and the snapshots handed out by [`tracked`] and
+/// friends. Biting those would break the creature itself, and a snapshot would list itself.
+#[cfg(feature = "std")]
+fn internally<T>(f: impl FnOnce() -> T) -> T {
+    let exempt = EXEMPT_HERE.with(|exempt| exempt.replace(true));
+    let result = f();
+    EXEMPT_HERE.with(|here| here.set(exempt));
+    result
+}
+
+// Without threads to exempt, the crate's own allocations are tracked like any other.
+#[cfg(not(feature = "std"))]
+fn internally<T>(f: impl FnOnce() -> T) -> T {
+    f()
+}
+
+// === Tags ===
+
+#[cfg(feature = "std")]
+const MAX_TAGS: usize = 255;
+
+// Tag names, interned to their position plus one. Only touched outside the allocator, except once
+// when awakening with `only_tag`.
+#[cfg(feature = "std")]
+static TAGS: Mutex<([&str; MAX_TAGS], usize)> = Mutex::new(([""; MAX_TAGS], 0));
+
+// The only tag eaten, or zero for all allocations.
+#[cfg(feature = "std")]
+static ONLY_TAG: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static CURRENT_TAG: core::cell::Cell<u8> = const { core::cell::Cell::new(0) };
+}
+
+#[cfg(feature = "std")]
+fn intern_tag(name: &'static str) -> u8 {
+    let mut tags = TAGS.lock().unwrap_or_else(|e| e.into_inner());
+    let (names, len) = &mut *tags;
+    if let Some(pos) = names[..*len].iter().position(|&known| known == name) {
+        return pos as u8 + 1;
+    }
+    assert!(*len < MAX_TAGS, "craturn supports at most {MAX_TAGS} tags");
+    names[*len] = name;
+    *len += 1;
+    *len as u8
+}
+
+/// Runs `f`, tagging every allocation the current thread makes meanwhile with `tag`. Together with
+/// [`AllocatorBuilder::only_tag`], this keeps the creature on a single part of the program, e.g.
+/// `craturn::with_tag("parser", || parse(input))`. Tags nest, the innermost one wins.
+///
+/// Tags are interned, and a program can use at most 255 different ones.
+#[cfg(feature = "std")]
+pub fn with_tag<R>(tag: &'static str, f: impl FnOnce() -> R) -> R {
+    struct Restore(u8);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_TAG.with(|tag| tag.set(self.0));
+        }
+    }
+    let id = intern_tag(tag);
+    let _restore = Restore(CURRENT_TAG.with(|tag| tag.replace(id)));
+    f()
+}
+
+// === Age ===
+
+// Milliseconds since awakening, refreshed by the eater on every tick and by every few hundredth
+// allocation, so tracking never needs to read the clock itself.
+#[cfg(feature = "std")]
+static COARSE_NOW_MS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "std")]
+const CLOCK_REFRESH_ALLOCS: usize = 256;
+static MIN_AGE_MS: AtomicU64 = AtomicU64::new(0);
+static MAX_AGE_MS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+#[cfg(feature = "std")]
+fn refresh_clock() -> u64 {
+    let now = now_ms();
+    COARSE_NOW_MS.fetch_max(now, Ordering::Relaxed);
+    now
+}
+
+/// Whether the allocation in `slot` is old enough, and young enough, to be eaten given
+/// [`AllocatorBuilder::min_age`] and [`AllocatorBuilder::max_age`].
+#[inline(always)]
+fn age_in_range(_slot: usize) -> bool {
+    #[cfg(feature = "std")]
+    {
+        let (min, max) = (
+            MIN_AGE_MS.load(Ordering::Relaxed),
+            MAX_AGE_MS.load(Ordering::Relaxed),
+        );
+        if min != 0 || max != u64::MAX {
+            let born = registry(_slot).born.load(Ordering::Relaxed);
+            let age = COARSE_NOW_MS.load(Ordering::Relaxed).saturating_sub(born);
+            return age >= min && age <= max;
+        }
+    }
+    true
+}
+
+/// Whether the allocation in `slot` may be eaten given [`AllocatorBuilder::only_tag`].
+#[inline(always)]
+fn tag_matches(_slot: usize) -> bool {
+    #[cfg(feature = "std")]
+    if !tag_allowed(registry(_slot).tag.load(Ordering::Relaxed)) {
+        return false;
+    }
+    true
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+fn tag_allowed(tag: u8) -> bool {
+    let only = ONLY_TAG.load(Ordering::Relaxed);
+    only == 0 || tag == only
+}
+
+/// Whether the allocation in `slot` passes the filters by size, tag, age and thread.
+#[inline(always)]
+fn wanted(slot: usize) -> bool {
+    let size = registry(slot).size.load(Ordering::Relaxed);
+    size_wanted(size) && tag_matches(slot) && age_in_range(slot) && thread_matches(slot)
+}
+
+#[inline(always)]
+fn size_wanted(size: usize) -> bool {
+    size >= SIZE_FILTER_MIN.load(Ordering::Relaxed)
+        && size <= SIZE_FILTER_MAX.load(Ordering::Relaxed)
+}
+
+/// Whether a block of `size` the current thread is allocating right now may be eaten, as
+/// `alloc_zeroed` asks before handing out garbage: the creature must be hungry, the thread not
+/// exempt, and the block past the filters by size, tag and thread. Too young for any age filter,
+/// the block is not held to it.
+#[inline(always)]
+fn edible_here(size: usize) -> bool {
+    #[cfg(feature = "std")]
+    if EXEMPT_HERE.with(|exempt| exempt.get())
+        || !thread_allowed(thread_tag())
+        || !tag_allowed(CURRENT_TAG.with(|tag| tag.get()))
+    {
+        return false;
+    }
+    hungry() && size_wanted(size)
+}
+
+// === Introspection ===
+
+/// Slots of the active set holding an allocation, with its address. Runs alongside allocations
+/// and the eater, so it may miss or repeat a slot moved in the meantime.
+fn live_slots() -> impl Iterator<Item = (&'static Slot, usize)> {
+    (0..ACTIVE_LEN.load(Ordering::Acquire)).filter_map(|pos| {
+        let slot = active(pos).load(Ordering::Acquire);
+        (slot != EMPTY).then(|| registry(slot)).and_then(|slot| {
+            let addr = slot.addr.load(Ordering::Acquire);
+            (addr != 0).then_some((slot, addr))
+        })
+    })
+}
+
+/// Pairs of `(addr, size)` for every tracked allocation. A best-effort snapshot: allocations made
+/// or freed while it is taken may or may not show up, and a size may be a moment out of date.
+///
+/// Allocates the returned `Vec`, so it must not be called from bite hooks.
+pub fn tracked() -> Vec<(usize, usize)> {
+    internally(|| {
+        live_slots()
+            .map(|(slot, addr)| (addr, slot.size.load(Ordering::Relaxed)))
+            .collect()
+    })
+}
+
+/// Pairs of `(addr, bites)` for the `n` live allocations bitten most, most bitten first. Shows
+/// whether the damage is spread or piles up on a few victims.
+///
+/// Allocates the returned `Vec`, so it must not be called from bite hooks.
+pub fn hottest_allocations(n: usize) -> Vec<(usize, usize)> {
+    internally(|| {
+        let mut hottest: Vec<_> = live_slots()
+            .map(|(slot, addr)| (addr, slot.bites.load(Ordering::Relaxed)))
+            .filter(|&(_, bites)| bites > 0)
+            .collect();
+        hottest.sort_unstable_by_key(|&(_, bites)| core::cmp::Reverse(bites));
+        hottest.truncate(n);
+        hottest
+    })
+}
+
+/// Pairs of `(addr, origin)` for every tracked allocation, where `origin` is the instruction
+/// pointer of the first frame above the global allocator at allocation time, or 0 if it could not
+/// be captured. Feed it to `addr2line` or a debugger to find who made the allocation.
+///
+/// Allocates the returned `Vec`, so it must not be called from bite hooks.
+#[cfg(feature = "origin")]
+pub fn allocation_sites() -> Vec<(usize, usize)> {
+    internally(|| {
+        live_slots()
+            .map(|(slot, addr)| (addr, slot.origin.load(Ordering::Relaxed)))
+            .collect()
+    })
+}
+
+#[cfg(feature = "origin")]
+mod origin {
+    use core::ffi::{c_int, c_void};
+
+    // Frames between the unwinder callback and the code that asked for memory: `capture` itself,
+    // `GlobalAlloc::alloc` and the `__rust_alloc` shim.
+    const SKIP: usize = 3;
+
+    struct Walk {
+        depth: usize,
+        ip: usize,
+    }
+
+    // The unwinder std already links against. It walks the stack without allocating, which is the
+    // one property that matters inside an allocator.
+    #[cfg(all(unix, not(target_env = "msvc")))]
+    extern "C" {
+        fn _Unwind_Backtrace(
+            trace: extern "C" fn(*mut c_void, *mut c_void) -> c_int,
+            arg: *mut c_void,
+        ) -> c_int;
+        fn _Unwind_GetIP(context: *mut c_void) -> usize;
+    }
+
+    #[cfg(all(unix, not(target_env = "msvc")))]
+    extern "C" fn frame(context: *mut c_void, arg: *mut c_void) -> c_int {
+        // _URC_NO_REASON keeps walking, _URC_END_OF_STACK stops.
+        const CONTINUE: c_int = 0;
+        const STOP: c_int = 5;
+        let walk = unsafe { &mut *(arg as *mut Walk) };
+        if walk.depth == SKIP {
+            walk.ip = unsafe { _Unwind_GetIP(context) };
+            return STOP;
+        }
+        walk.depth += 1;
+        CONTINUE
+    }
+
+    #[inline(never)]
+    pub(crate) fn capture() -> usize {
+        let mut walk = Walk { depth: 0, ip: 0 };
+        #[cfg(all(unix, not(target_env = "msvc")))]
+        unsafe {
+            _Unwind_Backtrace(frame, &mut walk as *mut Walk as *mut c_void);
+        }
+        walk.ip
+    }
+}
+
+#[cfg(any(feature = "exit-report", feature = "signal-stats"))]
+mod report {
+    use core::fmt::Write;
+    #[cfg(feature = "exit-report")]
+    use core::sync::atomic::Ordering;
+    #[cfg(feature = "exit-report")]
+    use core::time::Duration;
+
+    #[cfg(unix)]
+    extern "C" {
+        #[cfg(feature = "exit-report")]
+        fn atexit(callback: extern "C" fn()) -> core::ffi::c_int;
+        #[cfg(feature = "signal-stats")]
+        fn signal(signum: core::ffi::c_int, handler: extern "C" fn(core::ffi::c_int)) -> usize;
+        fn write(fd: core::ffi::c_int, buf: *const u8, count: usize) -> isize;
+    }
+
+    /// `SIGUSR1` is numbered differently across platforms, and `None` where we don't know it, which
+    /// leaves the signal alone.
+    #[cfg(all(feature = "signal-stats", unix))]
+    const SIGUSR1: Option<core::ffi::c_int> =
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            if cfg!(any(
+                target_arch = "mips",
+                target_arch = "mips32r6",
+                target_arch = "mips64",
+                target_arch = "mips64r6"
+            )) {
+                Some(16)
+            } else if cfg!(any(target_arch = "sparc", target_arch = "sparc64")) {
+                Some(30)
+            } else {
+                Some(10)
+            }
+        } else if cfg!(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly"
+        )) {
+            Some(30)
+        } else if cfg!(any(target_os = "solaris", target_os = "illumos")) {
+            Some(16)
+        } else {
+            None
+        };
+
+    /// A line of text on the stack, since reports are written while the heap is being torn down,
+    /// or from a signal handler.
+    struct Line {
+        buf: [u8; 1024],
+        len: usize,
+    }
+
+    impl Write for Line {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            let dest = self.buf.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+            dest.copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    impl Line {
+        fn new() -> Self {
+            Line {
+                buf: [0; 1024],
+                len: 0,
+            }
+        }
+
+        fn print(&self) {
+            #[cfg(unix)]
+            unsafe {
+                write(2, self.buf.as_ptr(), self.len);
+            }
+        }
+    }
+
+    #[cfg(feature = "exit-report")]
+    pub(crate) fn register_exit() {
+        #[cfg(unix)]
+        unsafe {
+            atexit(exit_report);
+        }
+    }
+
+    #[cfg(feature = "signal-stats")]
+    pub(crate) fn register_signal() {
+        #[cfg(unix)]
+        if let Some(signum) = SIGUSR1 {
+            unsafe {
+                signal(signum, dump_stats);
+            }
+        }
+    }
+
+    /// Only reads atomics and formats on the stack, so it is safe in a signal handler.
+    #[cfg(feature = "signal-stats")]
+    extern "C" fn dump_stats(_: core::ffi::c_int) {
+        let mut line = Line::new();
+        let _ = writeln!(line, "craturn: {:?}", super::stats());
+        line.print();
+    }
+
+    #[cfg(feature = "exit-report")]
+    extern "C" fn exit_report() {
+        let stats = super::stats();
+        let now = super::now_ms();
+        let oldest = super::live_slots()
+            .map(|(slot, _)| now.saturating_sub(slot.born.load(Ordering::Relaxed)))
+            .max()
+            .unwrap_or(0);
+        let mut line = Line::new();
+        let _ = writeln!(
+            line,
+            "craturn: {} bites, {} allocations hit, {} bytes flipped, \
+             oldest tracked allocation {:?}",
+            stats.total_bites,
+            stats.allocations_hit,
+            stats.bytes_flipped,
+            Duration::from_millis(oldest),
+        );
+        line.print();
+    }
+}
+
+// === Protection ===
+
+const MAX_PROTECTED: usize = 64;
+
+// Address ranges the eater never touches. An entry is claimed by CAS-ing `start` away from 0 and
+// released by zeroing it again.
+struct ProtectedRange {
+    start: AtomicUsize,
+    len: AtomicUsize,
+}
+
+static PROTECTED: [ProtectedRange; MAX_PROTECTED] = {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY_RANGE: ProtectedRange = ProtectedRange {
+        start: AtomicUsize::new(0),
+        len: AtomicUsize::new(0),
+    };
+    [EMPTY_RANGE; MAX_PROTECTED]
+};
+
+/// Why [`protect`] failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProtectError {
+    /// All 64 protection entries are taken. Drop a guard or protect a larger range instead.
+    TableFull,
+}
+
+impl core::fmt::Display for ProtectError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ProtectError::TableFull => {
+                write!(f, "all {MAX_PROTECTED} protection entries are taken")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ProtectError {}
+
+/// Keeps `len` bytes starting at `ptr` safe from the eater until the guard is dropped. Ranges
+/// may overlap each other and cover only part of an allocation, in which case the rest of it is
+/// still eaten. Any bite in flight lands before this returns.
+pub fn protect(ptr: *const u8, len: usize) -> Result<ProtectionGuard, ProtectError> {
+    let start = (ptr as usize).max(1);
+    let entry = PROTECTED
+        .iter()
+        .position(|range| {
+            let claimed = range
+                .start
+                .compare_exchange(0, start, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok();
+            if claimed {
+                range.len.store(len, Ordering::Release);
+            }
+            claimed
+        })
+        .ok_or(ProtectError::TableFull)?;
+    settle();
+    Ok(ProtectionGuard { entry })
+}
+
+#[inline(always)]
+fn is_protected(addr: usize, len: usize) -> bool {
+    PROTECTED.iter().any(|range| {
+        let start = range.start.load(Ordering::Acquire);
+        start != 0 && {
+            let end = start.saturating_add(range.len.load(Ordering::Acquire));
+            addr < end && start < addr + len
+        }
+    })
+}
+
+/// Removes its range from the protected set on drop.
+#[derive(Debug)]
+pub struct ProtectionGuard {
+    entry: usize,
+}
+
+impl Drop for ProtectionGuard {
+    fn drop(&mut self) {
+        let range = &PROTECTED[self.entry];
+        range.len.store(0, Ordering::Release);
+        range.start.store(0, Ordering::Release);
+    }
+}
+
+/// A boxed value the eater never bites. The box is simply dropped from tracking.
+///
+/// Only the box is kept safe, not what the value owns elsewhere on the heap: the elements of a
+/// `Protected<Vec<u8>>` are eaten like any others. Keep those out of reach with [`protect`].
+#[derive(Debug)]
+pub struct Protected<T> {
+    value: Box<T>,
+}
+
+impl<T> Protected<T> {
+    pub fn new(value: T) -> Self {
+        // Untracked before it is filled, so no bite lands in between.
+        let mut boxed = Box::<T>::new_uninit();
+        untrack(boxed.as_ptr() as usize);
+        boxed.write(value);
+        Self {
+            value: unsafe { boxed.assume_init() },
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        *self.value
+    }
+}
+
+impl<T> core::ops::Deref for Protected<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> core::ops::DerefMut for Protected<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+// === Canaries ===
+
+/// 64-bit FNV-1a hash of `bytes`. Cheap, and any single eaten word changes it.
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+/// Watches a buffer for bites, e.g. `let canary = Canary::new(&buf); ...; canary.verify()?`. Keeps
+/// a copy of the buffer the eater never bites, so a change is reported down to the byte.
+#[derive(Debug)]
+pub struct Canary<'a> {
+    watched: &'a [u8],
+    copy: Box<[u8]>,
+}
+
+/// The first byte a [`Canary`] found changed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    pub offset: usize,
+    pub expected: u8,
+    pub found: u8,
+}
+
+impl core::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "byte {} changed from {:#04x} to {:#04x}",
+            self.offset, self.expected, self.found
+        )
+    }
+}
+
+impl core::error::Error for Mismatch {}
+
+impl<'a> Canary<'a> {
+    pub fn new(watched: &'a [u8]) -> Self {
+        // Untracked before it is filled, so the copy starts out and stays intact.
+        let mut copy = vec![0; watched.len()].into_boxed_slice();
+        untrack(copy.as_ptr() as usize);
+        copy.copy_from_slice(watched);
+        Self { watched, copy }
+    }
+
+    /// Compares the buffer against how it looked at creation.
+    pub fn verify(&self) -> Result<(), Mismatch> {
+        match self
+            .watched
+            .iter()
+            .zip(&*self.copy)
+            .position(|(a, b)| a != b)
+        {
+            None => Ok(()),
+            Some(offset) => Err(Mismatch {
+                offset,
+                expected: self.copy[offset],
+                found: self.watched[offset],
+            }),
+        }
+    }
+}
+
+/// What a [`Tripwire`] caught: the checksum its value had, and the one it has now.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Tripped {
+    pub type_name: &'static str,
+    pub expected: u64,
+    pub found: u64,
+}
+
+impl core::fmt::Display for Tripped {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} was bitten, checksum {:#018x} is now {:#018x}",
+            self.type_name, self.expected, self.found
+        )
+    }
+}
+
+/// Called instead of panicking when a [`Tripwire`] trips, see [`on_trip`].
+pub type TripHandler = fn(&Tripped);
+
+static TRIP_HANDLER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Handles tripped [`Tripwire`]s with `handler` instead of panicking, e.g. to log and go on. The
+/// value is then trusted as it is, so each bite is reported once.
+pub fn on_trip(handler: TripHandler) {
+    TRIP_HANDLER.store(handler as *mut (), Ordering::Release);
+}
+
+fn trip(tripped: &Tripped) {
+    let handler = TRIP_HANDLER.load(Ordering::Acquire);
+    if handler.is_null() {
+        panic!("{tripped}");
+    }
+    // SAFETY: the only non-null values ever stored are `TripHandler` pointers.
+    unsafe { core::mem::transmute::<*mut (), TripHandler>(handler)(tripped) }
+}
+
+/// [`checksum`] as a `Hasher`, to hash values along with whatever they own on the heap.
+struct Fnv(u64);
+
+impl core::hash::Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = bytes.iter().fold(self.0, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01B3)
+        });
+    }
+}
+
+fn hash_of<T: core::hash::Hash>(value: &T) -> u64 {
+    let mut hasher = Fnv(checksum(&[]));
+    value.hash(&mut hasher);
+    core::hash::Hasher::finish(&hasher)
+}
+
+/// Fails fast on bites: checks the hash of its value, heap contents included, on every access
+/// and panics (or calls the [`on_trip`] handler) at the first use of a bitten value, for a stack
+/// trace pointing right at it. Changes made through `DerefMut` are taken in on the next access.
+#[derive(Debug)]
+pub struct Tripwire<T: core::hash::Hash> {
+    value: T,
+    sum: AtomicU64,
+    // Handed out mutably since the last check, so the sum is outdated.
+    stale: AtomicBool,
+}
+
+impl<T: core::hash::Hash> Tripwire<T> {
+    pub fn new(value: T) -> Self {
+        let sum = AtomicU64::new(hash_of(&value));
+        let stale = AtomicBool::new(false);
+        Self { value, sum, stale }
+    }
+
+    /// The value, checked one last time.
+    pub fn into_inner(self) -> T {
+        self.check();
+        self.value
+    }
+
+    /// Compares the value against its checksum without tripping.
+    pub fn verify(&self) -> Result<(), Tripped> {
+        let found = hash_of(&self.value);
+        if self.stale.swap(false, Ordering::Relaxed) {
+            self.sum.store(found, Ordering::Relaxed);
+            return Ok(());
+        }
+        let expected = self.sum.load(Ordering::Relaxed);
+        if found == expected {
+            return Ok(());
+        }
+        // Trusted from now on, so a bite is reported once.
+        self.sum.store(found, Ordering::Relaxed);
+        let type_name = core::any::type_name::<T>();
+        Err(Tripped {
+            type_name,
+            expected,
+            found,
+        })
+    }
+
+    fn check(&self) {
+        if let Err(tripped) = self.verify() {
+            trip(&tripped);
+        }
+    }
+}
+
+impl<T: core::hash::Hash> core::ops::Deref for Tripwire<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.check();
+        &self.value
+    }
+}
+
+impl<T: core::hash::Hash> core::ops::DerefMut for Tripwire<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.check();
+        self.stale.store(true, Ordering::Relaxed);
+        &mut self.value
+    }
+}
+
+// === Eater control ===
+
+static EVENTS: AtomicUsize = AtomicUsize::new(0);
+static EATER_STARTED: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "std")]
+static EATER: OnceLock<Thread> = OnceLock::new();
+#[cfg(feature = "std")]
+static EATER_HANDLE: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+static PAUSED: AtomicBool = AtomicBool::new(false);
+static STOPPED: AtomicBool = AtomicBool::new(false);
+
+/// Bumped whenever the eater should stop napping and re-read its appetite.
+static WAKE_EPOCH: AtomicUsize = AtomicUsize::new(0);
+
+fn wake_eater() {
+    WAKE_EPOCH.fetch_add(1, Ordering::AcqRel);
+    #[cfg(feature = "std")]
+    if let Some(eater) = EATER.get() {
+        eater.unpark();
+    }
+    #[cfg(feature = "std")]
+    for thread in EXTRA_THREADS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .flatten()
+    {
+        thread.unpark();
+    }
+}
+
+/// Sleeps for `duration`, returning early if [`wake_eater`] is called in the meantime or the
+/// creature, or the extra eater calling this, has been stopped.
+#[cfg(feature = "std")]
+fn nap(duration: Duration) {
+    let epoch = WAKE_EPOCH.load(Ordering::Acquire);
+    let deadline = Instant::now().checked_add(duration);
+    while WAKE_EPOCH.load(Ordering::Acquire) == epoch && !eater_stopped() {
+        match deadline {
+            None => thread::park(),
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+                thread::park_timeout(deadline - now);
+            }
+        }
+    }
+}
+
+// === Hibernation ===
+
+static HIBERNATING: AtomicBool = AtomicBool::new(false);
+
+/// Sends the creature to sleep until [`awaken_again`]. Unlike [`Allocator::pause`], the eater
+/// stops ticking altogether and blocks. Takes effect right away, not after the current nap: any
+/// bite in flight lands before this returns and none follows.
+pub fn hibernate() {
+    HIBERNATING.store(true, Ordering::SeqCst);
+    wake_eater();
+    settle();
+}
+
+/// Ends a [`hibernate`]. The eater picks up its usual rhythm from here.
+pub fn awaken_again() {
+    HIBERNATING.store(false, Ordering::SeqCst);
+    wake_eater();
+}
+
+/// Whether the creature is up and eating: awakened, and neither hibernating nor stopped.
+pub fn is_awake() -> bool {
+    EATER_STARTED.load(Ordering::Acquire)
+        && !HIBERNATING.load(Ordering::SeqCst)
+        && !STOPPED.load(Ordering::SeqCst)
+}
+
+// Bites in flight, so a caller calming the creature down can wait for the current one to land.
+static BITING: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static BITING_HERE: core::cell::Cell<bool> = const { core::cell::Cell::new(false) };
+}
+
+/// A bite in flight, landed on drop, so a panicking bite hook cannot leave [`settle`] waiting.
+struct InFlight {
+    // Whether this thread was already inside a bite, e.g. allocating from a hook.
+    #[cfg(feature = "std")]
+    nested: bool,
+}
+
+impl InFlight {
+    fn new() -> Self {
+        BITING.fetch_add(1, Ordering::SeqCst);
+        InFlight {
+            #[cfg(feature = "std")]
+            nested: BITING_HERE.with(|here| here.replace(true)),
+        }
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        BITING_HERE.with(|here| here.set(self.nested));
+        BITING.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Waits until no bite is in flight, unless called from inside a bite, e.g. by a hook.
+fn settle() {
+    #[cfg(feature = "std")]
+    if BITING_HERE.with(|here| here.get()) {
+        return;
+    }
+    while BITING.load(Ordering::SeqCst) != 0 {
+        core::hint::spin_loop();
+    }
+}
+
+// === Holding breath ===
+
+static BREATH_HELD: AtomicUsize = AtomicUsize::new(0);
+
+/// Suppresses every bite, and every lie of [`dirty_zeroed`](AllocatorBuilder::dirty_zeroed), until
+/// the returned guard is dropped. Guards nest and may be held by any
+/// number of threads at once, the creature only eats again once the last one is gone. Unlike
+/// `feed`, the quiet period lasts as long as the guard does, e.g. for a whole test setup.
+///
+/// Nothing is allocated and any bite in flight lands before this returns. Hunger changes made
+/// meanwhile take effect, but only show once the breath is let out.
+pub fn hold_breath() -> BreathGuard {
+    BREATH_HELD.fetch_add(1, Ordering::SeqCst);
+    settle();
+    BreathGuard { _private: () }
+}
+
+/// Same as [`hold_breath`], e.g. `let _frozen = craturn::freeze();` around writing a final report.
+pub fn freeze() -> BreathGuard {
+    hold_breath()
+}
+
+/// Returned by [`hold_breath`]. Bites resume once every guard is dropped, including by unwinding.
+#[must_use = "the creature breathes again as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct BreathGuard {
+    _private: (),
+}
+
+impl Drop for BreathGuard {
+    fn drop(&mut self) {
+        BREATH_HELD.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+// === Feeding ===
+
+// Milliseconds since awakening until which the creature leaves memory alone.
+#[cfg(feature = "std")]
+static FED_UNTIL: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "std")]
+fn now_ms() -> u64 {
+    millis(AWAKENED_AT.get_or_init(Instant::now).elapsed())
+}
+
+/// Satiates the creature for `duration`: no bite lands until it is over, starting from when this
+/// returns. Overlapping calls extend the quiet period, never shorten it. Hunger is unaffected.
+#[cfg(feature = "std")]
+pub fn feed(duration: Duration) {
+    FED_UNTIL.fetch_max(now_ms().saturating_add(millis(duration)), Ordering::SeqCst);
+    settle();
+}
+
+/// Whether the creature is still digesting a [`feed`].
+#[cfg(feature = "std")]
+pub fn is_fed() -> bool {
+    now_ms() < FED_UNTIL.load(Ordering::SeqCst)
+}
+
+// === Active windows ===
+
+// With a window set, bites only land while the time since awakening, modulo the period, lies
+// between its start and end. A period of zero makes it a single window.
+#[cfg(feature = "std")]
+static WINDOWED: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "std")]
+static WINDOW_START_MS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "std")]
+static WINDOW_END_MS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "std")]
+static WINDOW_PERIOD_MS: AtomicU64 = AtomicU64::new(0);
+// Time spent active under earlier windows, and when the current one was set.
+#[cfg(feature = "std")]
+static ACTIVE_BEFORE_MS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "std")]
+static WINDOW_SET_AT_MS: AtomicU64 = AtomicU64::new(0);
+
+/// Only lets the creature bite from `start` to `end` after awakening, repeated every `period`,
+/// e.g. for 5 seconds of every minute. A zero `period` opens the window just once. The eater
+/// sleeps through the rest and bites as soon as a window opens, so even a window shorter than the
+/// interval sees a bite. Hunger changed meanwhile takes effect from then on.
+#[cfg(feature = "std")]
+pub fn set_active_window(start: Duration, end: Duration, period: Duration) {
+    install_window(Some((millis(start), millis(end), millis(period))));
+}
+
+/// Lets the creature bite around the clock again.
+#[cfg(feature = "std")]
+pub fn clear_active_window() {
+    install_window(None);
+}
+
+#[cfg(feature = "std")]
+fn install_window(window: Option<(u64, u64, u64)>) {
+    let now = millis(since_awakening());
+    ACTIVE_BEFORE_MS.store(active_ms(now), Ordering::Relaxed);
+    WINDOW_SET_AT_MS.store(now, Ordering::Relaxed);
+    WINDOWED.store(false, Ordering::SeqCst);
+    if let Some((start, end, period)) = window {
+        WINDOW_START_MS.store(start, Ordering::Relaxed);
+        WINDOW_END_MS.store(end, Ordering::Relaxed);
+        WINDOW_PERIOD_MS.store(period, Ordering::Relaxed);
+        WINDOWED.store(true, Ordering::SeqCst);
+    }
+    wake_eater();
+}
+
+#[cfg(feature = "std")]
+fn active_window() -> Option<(u64, u64, u64)> {
+    WINDOWED.load(Ordering::SeqCst).then(|| {
+        (
+            WINDOW_START_MS.load(Ordering::Relaxed),
+            WINDOW_END_MS.load(Ordering::Relaxed),
+            WINDOW_PERIOD_MS.load(Ordering::Relaxed),
+        )
+    })
+}
+
+/// How long until the window opens. `None` while it is open or without a window, and
+/// `Duration::MAX` when it never opens again.
+#[cfg(feature = "std")]
+fn until_window_opens() -> Option<Duration> {
+    opens_in(active_window()?, now_ms())
+}
+
+/// How long after `now` the window opens, as for [`until_window_opens`].
+#[cfg(feature = "std")]
+fn opens_in((start, end, period): (u64, u64, u64), now: u64) -> Option<Duration> {
+    if start >= end || period != 0 && start >= period {
+        return Some(Duration::MAX);
+    }
+    let at = if period == 0 { now } else { now % period };
+    let until = if at < start {
+        start - at
+    } else if at < end {
+        return None;
+    } else if period == 0 {
+        return Some(Duration::MAX);
+    } else {
+        period - at + start
+    };
+    Some(Duration::from_millis(until))
+}
+
+/// Milliseconds the window was open during the first `t` after awakening.
+#[cfg(feature = "std")]
+fn open_before((start, end, period): (u64, u64, u64), t: u64) -> u64 {
+    let (start, end) = match period {
+        0 => (start, end),
+        _ => (start.min(period), end.min(period)),
+    };
+    let end = end.max(start);
+    let within = |t: u64| t.clamp(start, end) - start;
+    match period {
+        0 => within(t),
+        _ => t / period * (end - start) + within(t % period),
+    }
+}
+
+/// Milliseconds the creature was allowed to bite during the first `now` after awakening.
+#[cfg(feature = "std")]
+fn active_ms(now: u64) -> u64 {
+    let since = WINDOW_SET_AT_MS.load(Ordering::Relaxed);
+    let active = match active_window() {
+        None => now.saturating_sub(since),
+        Some(window) => open_before(window, now).saturating_sub(open_before(window, since)),
+    };
+    ACTIVE_BEFORE_MS.load(Ordering::Relaxed) + active
+}
+
+// === Appetite ===
+
+// The appetite the eater consults on every tick. Installed from the `Allocator` on awakening and
+// replaced wholesale by `set_hunger`.
+static HUNGER: AtomicU8 = AtomicU8::new(Hunger::Hungry as u8);
+static INTERVAL_MS: AtomicU64 = AtomicU64::new(0);
+static WORDS: AtomicUsize = AtomicUsize::new(0);
+static MASK: AtomicU64 = AtomicU64::new(0);
+// The `masks` list, cycled through one bite at a time. Empty when unset.
+static MASKS: AtomicPtr<u64> = AtomicPtr::new(ptr::null_mut());
+static MASKS_LEN: AtomicUsize = AtomicUsize::new(0);
+static MASKS_NEXT: AtomicUsize = AtomicUsize::new(0);
+static SLOTS_PER_BITE: AtomicUsize = AtomicUsize::new(1);
+static KIND: AtomicU8 = AtomicU8::new(CorruptionKind::Xor as u8);
+static FILTER_MASK: AtomicU64 = AtomicU64::new(0);
+static FILTER_VALUE: AtomicU64 = AtomicU64::new(0);
+static OFFSET: AtomicU8 = AtomicU8::new(OffsetStrategy::Random.to_parts().0);
+static FIXED_OFFSET: AtomicUsize = AtomicUsize::new(0);
+static SCATTER: AtomicBool = AtomicBool::new(false);
+static BLAST_RADIUS: AtomicUsize = AtomicUsize::new(0);
+static GRANULARITY: AtomicUsize = AtomicUsize::new(8);
+static SOFT: AtomicBool = AtomicBool::new(false);
+static CRUEL: AtomicBool = AtomicBool::new(false);
+static LANE_ALIGNED: AtomicBool = AtomicBool::new(false);
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+static POISSON: AtomicBool = AtomicBool::new(false);
+// Bits of the `f64` jitter fraction.
+static JITTER: AtomicU64 = AtomicU64::new(0);
+static SENTINEL: AtomicU64 = AtomicU64::new(DEFAULT_SENTINEL);
+static POISON_ON_FREE: AtomicBool = AtomicBool::new(false);
+static POISON: AtomicU64 = AtomicU64::new(0);
+static VICTIM: AtomicU8 = AtomicU8::new(VictimPolicy::Random as u8);
+static ROUND_ROBIN: AtomicUsize = AtomicUsize::new(0);
+static NEXT_BITE: AtomicUsize = AtomicUsize::new(0);
+static MAX_BITES: AtomicUsize = AtomicUsize::new(usize::MAX);
+// Words left to eat, `u64::MAX` for no limit.
+static BITE_BUDGET: AtomicU64 = AtomicU64::new(u64::MAX);
+static WARMUP_ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static STOP_ALLOCS: AtomicUsize = AtomicUsize::new(usize::MAX);
+static MIN_SIZE: AtomicUsize = AtomicUsize::new(64);
+static MAX_SIZE: AtomicUsize = AtomicUsize::new(usize::MAX);
+static SIZE_FILTER_MIN: AtomicUsize = AtomicUsize::new(0);
+static SIZE_FILTER_MAX: AtomicUsize = AtomicUsize::new(usize::MAX);
+static DIRTY_ZEROED: AtomicU64 = AtomicU64::new(0);
+static BITE_CHANCE: AtomicU64 = AtomicU64::new(u64::MAX);
+static HUNGER_PINNED: AtomicBool = AtomicBool::new(false);
+
+/// Changes the appetite of a running creature to the given preset. Bites stop on the next tick
+/// when set to [`Hunger::Full`], and resume without waiting for a new allocation otherwise. The
+/// preset overrides the [schedule](set_schedule) until [`clear_hunger`] hands control back.
+pub fn set_hunger(hunger: Hunger) {
+    HUNGER_PINNED.store(true, Ordering::Relaxed);
+    install_hunger(hunger);
+    wake_eater();
+}
+
+/// Undoes [`set_hunger`]: the creature follows its schedule again, starting with the phase due
+/// right now. Without a phase due, the current appetite stays.
+pub fn clear_hunger() {
+    HUNGER_PINNED.store(false, Ordering::Relaxed);
+    #[cfg(feature = "std")]
+    if let Some(hunger) = scheduled_hunger() {
+        install_hunger(hunger);
+    }
+    wake_eater();
+}
+
+fn install_hunger(hunger: Hunger) {
+    let preset = BiteConfig::from_hunger(hunger);
+    HUNGER.store(hunger as u8, Ordering::Relaxed);
+    INTERVAL_MS.store(millis(preset.interval), Ordering::Relaxed);
+    WORDS.store(preset.words, Ordering::Relaxed);
+    MASK.store(preset.mask, Ordering::Relaxed);
+    SLOTS_PER_BITE.store(preset.slots_per_bite, Ordering::Relaxed);
+}
+
+/// Changes what bites do to eaten words, see [`AllocatorBuilder::kind`].
+pub fn set_kind(kind: CorruptionKind) {
+    KIND.store(kind as u8, Ordering::Relaxed);
+}
+
+/// Changes which words are worth eating, see [`AllocatorBuilder::filter`].
+pub fn set_filter(filter: CorruptionFilter) {
+    FILTER_MASK.store(filter.mask, Ordering::Relaxed);
+    FILTER_VALUE.store(filter.value, Ordering::Relaxed);
+}
+
+#[inline(always)]
+fn corruption_filter() -> CorruptionFilter {
+    CorruptionFilter {
+        mask: FILTER_MASK.load(Ordering::Relaxed),
+        value: FILTER_VALUE.load(Ordering::Relaxed),
+    }
+}
+
+/// Lets the creature eat `budget` more words, counting every word of every allocation bitten on
+/// its own, and leaves it dormant once they are eaten. Another call refills the budget and
+/// biting resumes; `u64::MAX` lifts the limit. Independent of
+/// [`max_bites`](AllocatorBuilder::max_bites), both apply.
+pub fn set_bite_budget(budget: u64) {
+    BITE_BUDGET.store(budget, Ordering::SeqCst);
+    wake_eater();
+}
+
+/// Takes one word off the budget, or returns `false` when it is used up.
+#[inline(always)]
+fn spend_budget() -> bool {
+    BITE_BUDGET
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |budget| match budget {
+            u64::MAX => Some(budget),
+            _ => budget.checked_sub(1),
+        })
+        .is_ok()
+}
+
+/// Changes the chance of a tick of the eater to bite, see [`AllocatorBuilder::probability`].
+pub fn set_probability(probability: f64) {
+    BITE_CHANCE.store(chance(probability), Ordering::Relaxed);
+}
+
+/// Changes which allocations bites go for, see [`AllocatorBuilder::victim`].
+pub fn set_victim(victim: VictimPolicy) {
+    VICTIM.store(victim as u8, Ordering::Relaxed);
+}
+
+/// Changes where in an allocation bites land, see [`AllocatorBuilder::offset`].
+pub fn set_offset(offset: OffsetStrategy) {
+    let (offset, fixed_offset) = offset.to_parts();
+    FIXED_OFFSET.store(fixed_offset, Ordering::Relaxed);
+    OFFSET.store(offset, Ordering::Relaxed);
+}
+
+/// Changes the pattern [`CorruptionKind::Sentinel`] bites write, see
+/// [`AllocatorBuilder::sentinel`].
+pub fn set_sentinel(sentinel: u64) {
+    SENTINEL.store(sentinel, Ordering::Relaxed);
+}
+
+/// The pattern [`CorruptionKind::Sentinel`] bites currently write.
+pub fn sentinel() -> u64 {
+    SENTINEL.load(Ordering::Relaxed)
+}
+
+/// Changes the smallest allocation the creature tracks and bites. Allocations made before a
+/// lowered threshold stay untracked, while already tracked ones below a raised threshold are
+/// simply left alone.
+pub fn set_min_size(min_size: usize) {
+    MIN_SIZE.store(min_size, Ordering::Relaxed);
+}
+
+/// Aims bites at allocations from `min` to `max` bytes, e.g. only the large I/O buffers, without
+/// touching what gets tracked: unlike [`set_min_size`], narrowing and widening the filter again
+/// brings everything tracked meanwhile back into reach. `set_size_filter(0, usize::MAX)` lifts it.
+pub fn set_size_filter(min: usize, max: usize) {
+    SIZE_FILTER_MIN.store(min, Ordering::Relaxed);
+    SIZE_FILTER_MAX.store(max, Ordering::Relaxed);
+}
+
+/// The preset the creature currently follows. A custom-built allocator reports the preset its
+/// builder started from.
+pub fn hunger() -> Hunger {
+    Hunger::from_u8(HUNGER.load(Ordering::Relaxed))
+}
+
+/// The interval between bites the creature currently keeps, before any jitter.
+pub fn interval() -> Duration {
+    Duration::from_millis(INTERVAL_MS.load(Ordering::Relaxed))
+}
+
+/// The preset in effect right now: the one of a [rampage](rampage_for), else the one pinned by
+/// [`set_hunger`], else the phase of the [schedule](set_schedule) due by now, else [`hunger`].
+pub fn effective_hunger() -> Hunger {
+    #[cfg(feature = "std")]
+    if rampaging() {
+        return Hunger::from_u8(RAMPAGE_HUNGER.load(Ordering::SeqCst));
+    }
+    #[cfg(feature = "std")]
+    if !HUNGER_PINNED.load(Ordering::Relaxed) {
+        if let Some(hunger) = scheduled_hunger() {
+            return hunger;
+        }
+    }
+    hunger()
+}
+
+// === Schedule ===
+
+// Phases of hunger over the lifetime of the process, counted from the first bite. Installed from
+// `escalate` on awakening and replaced by `set_schedule`.
+#[cfg(feature = "std")]
+static SCHEDULE: Mutex<&'static [(Duration, Hunger)]> = Mutex::new(&[]);
+#[cfg(feature = "std")]
+static SCHEDULE_START_MS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// Replaces the schedule of a running creature, see [`AllocatorBuilder::escalate`]. Each
+/// `(after, hunger)` phase begins once `after` has passed since the first bite, and the last one
+/// lasts for good. An empty schedule leaves the appetite as it is.
+#[cfg(feature = "std")]
+pub fn set_schedule(phases: &'static [(Duration, Hunger)]) {
+    store_schedule(phases);
+    if !HUNGER_PINNED.load(Ordering::Relaxed) {
+        if let Some(hunger) = scheduled_hunger() {
+            install_hunger(hunger);
+        }
+    }
+    wake_eater();
+}
+
+#[cfg(feature = "std")]
+fn store_schedule(phases: &'static [(Duration, Hunger)]) {
+    *SCHEDULE.lock().unwrap_or_else(|e| e.into_inner()) = phases;
+}
+
+#[cfg(feature = "std")]
+fn schedule() -> &'static [(Duration, Hunger)] {
+    *SCHEDULE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Time since the first bite was due, or `None` before then.
+#[cfg(feature = "std")]
+fn since_schedule_start() -> Option<Duration> {
+    let start = SCHEDULE_START_MS.load(Ordering::Relaxed);
+    (start != u64::MAX).then(|| Duration::from_millis(now_ms().saturating_sub(start)))
+}
+
+/// How many phases of `schedule` have begun by `elapsed`.
+#[cfg(feature = "std")]
+fn phases_begun(schedule: &[(Duration, Hunger)], elapsed: Duration) -> usize {
+    schedule.partition_point(|&(at, _)| at <= elapsed)
+}
+
+#[cfg(feature = "std")]
+fn scheduled_hunger() -> Option<Hunger> {
+    let schedule = schedule();
+    let begun = phases_begun(schedule, since_schedule_start()?);
+    begun.checked_sub(1).map(|phase| schedule[phase].1)
+}
+
+// === Rampage ===
+
+#[cfg(feature = "std")]
+static RAMPAGE_UNTIL_MS: AtomicU64 = AtomicU64::new(0);
+#[cfg(feature = "std")]
+static RAMPAGE_HUNGER: AtomicU8 = AtomicU8::new(Hunger::Full as u8);
+
+/// Goes wild at `hunger` for `duration`, then back to the appetite from before, which is left
+/// untouched meanwhile. Overlapping rampages last until the later end, at the greater hunger.
+/// Bites taken during a rampage are marked in [`BiteEvent::rampage`].
+#[cfg(feature = "std")]
+pub fn rampage_for(duration: Duration, hunger: Hunger) {
+    // A rampage that is over has no say in the hunger of the next one.
+    if !rampaging() {
+        RAMPAGE_HUNGER.store(Hunger::Full as u8, Ordering::SeqCst);
+    }
+    RAMPAGE_HUNGER.fetch_max(hunger as u8, Ordering::SeqCst);
+    let until = now_ms().saturating_add(millis(duration));
+    RAMPAGE_UNTIL_MS.fetch_max(until, Ordering::SeqCst);
+    wake_eater();
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+fn rampaging() -> bool {
+    let until = RAMPAGE_UNTIL_MS.load(Ordering::SeqCst);
+    until != 0 && now_ms() < until
+}
+
+/// The appetite of the rampage going on, if any.
+#[cfg(feature = "std")]
+fn rampage() -> Option<BiteConfig> {
+    let hunger = Hunger::from_u8(RAMPAGE_HUNGER.load(Ordering::SeqCst));
+    rampaging().then(|| BiteConfig::from_hunger(hunger))
+}
+
+#[cfg(feature = "std")]
+fn until_rampage_ends() -> Option<Duration> {
+    let until = RAMPAGE_UNTIL_MS.load(Ordering::SeqCst);
+    rampaging().then(|| Duration::from_millis(until.saturating_sub(now_ms())))
+}
+
+#[inline(always)]
+fn millis(duration: Duration) -> u64 {
+    duration.as_millis().min(u64::MAX as u128) as u64
+}
+
+#[inline(always)]
+fn size_in_range(size: usize) -> bool {
+    size >= MIN_SIZE.load(Ordering::Relaxed) && size <= MAX_SIZE.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+fn bite_offset() -> Duration {
+    if let Some(eater) = extra_eater() {
+        return Duration::from_millis(eater.interval_ms.load(Ordering::Relaxed));
+    }
+    if let Some(rampage) = rampage() {
+        return rampage.interval;
+    }
+    Duration::from_millis(INTERVAL_MS.load(Ordering::Relaxed))
+}
+
+#[inline(always)]
+fn corruption_shape() -> (usize, u64, CorruptionKind) {
+    let kind = CorruptionKind::from_u8(KIND.load(Ordering::Relaxed));
+    #[cfg(feature = "std")]
+    if let Some(eater) = extra_eater() {
+        let words = eater.words.load(Ordering::Relaxed);
+        return (words, eater.mask.load(Ordering::Relaxed), kind);
+    }
+    #[cfg(feature = "std")]
+    if let Some(rampage) = rampage() {
+        return (rampage.words, rampage.mask, kind);
+    }
+    (WORDS.load(Ordering::Relaxed), next_mask(), kind)
+}
+
+/// The next mask of the `masks` list, or the plain one without a list.
+#[inline(always)]
+fn next_mask() -> u64 {
+    let len = MASKS_LEN.load(Ordering::Acquire);
+    if len == 0 {
+        return MASK.load(Ordering::Relaxed);
+    }
+    let n = MASKS_NEXT.fetch_add(1, Ordering::Relaxed) % len;
+    // Safety: installed once from a `&'static [u64]` of `len` masks.
+    unsafe { *MASKS.load(Ordering::Relaxed).add(n) }
+}
+
+#[inline(always)]
+fn victim_policy() -> VictimPolicy {
+    #[cfg(feature = "std")]
+    if let Some(eater) = extra_eater() {
+        return VictimPolicy::from_u8(eater.victim.load(Ordering::Relaxed));
+    }
+    VictimPolicy::from_u8(VICTIM.load(Ordering::Relaxed))
+}
+
+#[inline(always)]
+fn offset_strategy() -> OffsetStrategy {
+    OffsetStrategy::from_parts(
+        OFFSET.load(Ordering::Relaxed),
+        FIXED_OFFSET.load(Ordering::Relaxed),
+    )
+}
+
+#[inline(always)]
+fn scatter() -> bool {
+    SCATTER.load(Ordering::Relaxed)
+}
+
+#[inline(always)]
+fn blast_radius() -> usize {
+    BLAST_RADIUS.load(Ordering::Relaxed)
+}
+
+#[inline(always)]
+fn granularity() -> usize {
+    GRANULARITY.load(Ordering::Relaxed)
+}
+
+#[inline(always)]
+fn soft() -> bool {
+    SOFT.load(Ordering::Relaxed)
+}
+
+#[inline(always)]
+fn cruel() -> bool {
+    CRUEL.load(Ordering::Relaxed)
+}
+
+#[inline(always)]
+fn lane_aligned() -> bool {
+    LANE_ALIGNED.load(Ordering::Relaxed)
+}
+
+#[inline(always)]
+fn dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+// === Statistics ===
+
+// Counters, cleared by `reset_stats`. Bites are counted apart from `EVENTS`, which is the budget
+// and must survive a reset.
+static BITES: AtomicUsize = AtomicUsize::new(0);
+static WORDS_FLIPPED: AtomicUsize = AtomicUsize::new(0);
+static WORDS_UNCHANGED: AtomicUsize = AtomicUsize::new(0);
+static POINTERS_SPARED: AtomicUsize = AtomicUsize::new(0);
+static BYTES_FLIPPED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS_HIT: AtomicUsize = AtomicUsize::new(0);
+static TICKS: AtomicUsize = AtomicUsize::new(0);
+static TICKS_SKIPPED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS_TRACKED: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS_MISSED: AtomicUsize = AtomicUsize::new(0);
+static SKIPPED_TOO_SMALL: AtomicUsize = AtomicUsize::new(0);
+static SKIPPED_TOO_LARGE: AtomicUsize = AtomicUsize::new(0);
+static DIRTY_ZEROED_LIES: AtomicUsize = AtomicUsize::new(0);
+
+// Gauges, describing what is alive right now.
+static SLOTS_IN_USE: AtomicUsize = AtomicUsize::new(0);
+static BYTES_TRACKED: AtomicUsize = AtomicUsize::new(0);
+
+/// Snapshot of what the creature has done so far. Counters are read independently, so a snapshot
+/// taken while the eater runs may be slightly inconsistent.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// Bites that changed at least one word.
+    pub total_bites: usize,
+    /// Words changed across all bites.
+    pub words_flipped: usize,
+    /// Words bites landed on without changing them, e.g. zeroing a word that was zero already.
+    /// These are not counted as damage.
+    pub words_unchanged: usize,
+    /// Words left alone because they looked like pointers, see [`AllocatorBuilder::soft`].
+    pub pointers_spared: usize,
+    /// Bytes changed across all bites.
+    pub bytes_flipped: usize,
+    /// Allocations changed by at least one bite.
+    pub allocations_hit: usize,
+    /// Live tracked allocations no bite has changed yet.
+    pub never_bitten: usize,
+    /// Ticks of the eater while hungry, see [`AllocatorBuilder::probability`].
+    pub ticks: usize,
+    /// Ticks that did not bite because the dice said so.
+    pub ticks_skipped: usize,
+    /// Allocations that made it into the registry.
+    pub allocations_tracked: usize,
+    /// Allocations in the size window that could not be tracked, e.g. with the registry full.
+    pub allocations_missed: usize,
+    /// Length of the active set, i.e. live allocations the eater can choose from.
+    pub active_tracked: usize,
+    /// Registry slots currently handed out.
+    pub slots_in_use: usize,
+    /// Bytes of live tracked allocations.
+    pub bytes_tracked: usize,
+    /// Allocations never tracked because they were smaller than the minimum size.
+    pub skipped_too_small: usize,
+    /// Allocations never tracked because they were larger than the maximum size.
+    pub skipped_too_large: usize,
+    /// Calls to `alloc_zeroed` that handed out dirty memory.
+    pub dirty_zeroed: usize,
+    /// Words of a [replayed trace](replay_trace) whose allocation was not there.
+    pub replay_divergences: usize,
+    /// Words left in the [bite budget](set_bite_budget), `None` without one.
+    pub budget_remaining: Option<u64>,
+    /// Time since the creature was awakened. Always zero without `std`.
+    pub eater_uptime: Duration,
+    /// Part of the uptime spent inside the [active window](set_active_window), all of it without
+    /// one.
+    pub active_time: Duration,
+    /// Part of the uptime spent outside the active window.
+    pub dormant_time: Duration,
+}
+
+/// What the creature has done so far. Only reads atomics, so it is cheap enough for a hot loop.
+pub fn stats() -> Stats {
+    let uptime = since_awakening();
+    #[cfg(feature = "std")]
+    let active = Duration::from_millis(active_ms(millis(uptime))).min(uptime);
+    #[cfg(not(feature = "std"))]
+    let active = uptime;
+    Stats {
+        total_bites: BITES.load(Ordering::Relaxed),
+        words_flipped: WORDS_FLIPPED.load(Ordering::Relaxed),
+        words_unchanged: WORDS_UNCHANGED.load(Ordering::Relaxed),
+        pointers_spared: POINTERS_SPARED.load(Ordering::Relaxed),
+        bytes_flipped: BYTES_FLIPPED.load(Ordering::Relaxed),
+        allocations_hit: ALLOCATIONS_HIT.load(Ordering::Relaxed),
+        never_bitten: NEVER_BITTEN.load(Ordering::Relaxed),
+        ticks: TICKS.load(Ordering::Relaxed),
+        ticks_skipped: TICKS_SKIPPED.load(Ordering::Relaxed),
+        allocations_tracked: ALLOCATIONS_TRACKED.load(Ordering::Relaxed),
+        allocations_missed: ALLOCATIONS_MISSED.load(Ordering::Relaxed),
+        active_tracked: ACTIVE_LEN.load(Ordering::Relaxed),
+        slots_in_use: SLOTS_IN_USE.load(Ordering::Relaxed),
+        bytes_tracked: BYTES_TRACKED.load(Ordering::Relaxed),
+        skipped_too_small: SKIPPED_TOO_SMALL.load(Ordering::Relaxed),
+        skipped_too_large: SKIPPED_TOO_LARGE.load(Ordering::Relaxed),
+        dirty_zeroed: DIRTY_ZEROED_LIES.load(Ordering::Relaxed),
+        replay_divergences: REPLAY_DIVERGENCES.load(Ordering::Relaxed),
+        budget_remaining: Some(BITE_BUDGET.load(Ordering::SeqCst)).filter(|&b| b != u64::MAX),
+        eater_uptime: uptime,
+        active_time: active,
+        dormant_time: uptime.saturating_sub(active),
+    }
+}
+
+/// Zeroes the counters in [`Stats`] and the [`histogram`]. What is alive right now, and the bite
+/// budget, are kept.
+pub fn reset_stats() {
+    for counter in [
+        &BITES,
+        &WORDS_FLIPPED,
+        &WORDS_UNCHANGED,
+        &POINTERS_SPARED,
+        &BYTES_FLIPPED,
+        &ALLOCATIONS_HIT,
+        &TICKS,
+        &TICKS_SKIPPED,
+        &ALLOCATIONS_TRACKED,
+        &ALLOCATIONS_MISSED,
+        &SKIPPED_TOO_SMALL,
+        &SKIPPED_TOO_LARGE,
+        &DIRTY_ZEROED_LIES,
+        &REPLAY_DIVERGENCES,
+    ]
+    .into_iter()
+    .chain(&SIZE_HISTOGRAM)
+    {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+// Allocations of every size, tracked or not. Bucket `k` counts sizes up to `2^k`, and above
+// `2^(k - 1)` for `k > 0`.
+static SIZE_HISTOGRAM: [AtomicUsize; usize::BITS as usize + 1] =
+    [const { AtomicUsize::new(0) }; usize::BITS as usize + 1];
+
+#[inline(always)]
+fn count_size(size: usize) {
+    let bucket = usize::BITS - size.saturating_sub(1).leading_zeros();
+    SIZE_HISTOGRAM[bucket as usize].fetch_add(1, Ordering::Relaxed);
+}
+
+/// How many allocations of which size the program made so far, tracked by the creature or not, as
+/// `(upper_bound, count)` pairs of power-of-two buckets. Empty buckets are left out. Handy for
+/// picking [`AllocatorBuilder::min_size`] and [`AllocatorBuilder::max_size`].
+pub fn histogram() -> Vec<(usize, usize)> {
+    internally(|| {
+        SIZE_HISTOGRAM
+            .iter()
+            .enumerate()
+            .map(|(bucket, count)| {
+                let upper_bound = 1usize.checked_shl(bucket as u32).unwrap_or(usize::MAX);
+                (upper_bound, count.load(Ordering::Relaxed))
+            })
+            .filter(|&(_, count)| count > 0)
+            .collect()
+    })
+}
+
+// === Bite log ===
+
+const BITE_LOG_LEN: usize = 1024;
+
+#[cfg(feature = "std")]
+static AWAKENED_AT: OnceLock<Instant> = OnceLock::new();
+
+#[cfg(feature = "std")]
+fn since_awakening() -> Duration {
+    AWAKENED_AT.get().map(Instant::elapsed).unwrap_or_default()
+}
+
+// No clock without `std`, bites are all stamped at zero.
+#[cfg(not(feature = "std"))]
+fn since_awakening() -> Duration {
+    Duration::ZERO
+}
+
+/// A single eaten word.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BiteEvent {
+    /// Time since the creature was awakened. Always zero without `std`.
+    pub timestamp: Duration,
+    /// Which bite the word belongs to. Words eaten together share it, so the offsets of a
+    /// scattered bite are those of the events with the same `bite`.
+    pub bite: usize,
+    /// What the bite did. Blocks handed out dirty by `alloc_zeroed` show up as
+    /// [`CorruptionKind::Sentinel`] bites of `0xA5` bytes.
+    pub kind: CorruptionKind,
+    /// How long the eater napped before the tick that took the bite, which varies with
+    /// [`AllocatorBuilder::poisson`]. Zero for bites taken by hand.
+    pub interval: Duration,
+    /// Whether the bite was taken during a [rampage](rampage_for).
+    pub rampage: bool,
+    /// Whether the allocation looked like text right before the bite, see [`VictimPolicy::Text`].
+    /// Only checked under that policy, and `false` otherwise.
+    pub text: bool,
+    /// Whether the eaten word looked like a heap pointer, then in `before`. The breadcrumb for a
+    /// segfault far away, see [`AllocatorBuilder::cruel`].
+    pub pointer: bool,
+    /// Start of the bitten allocation.
+    pub addr: usize,
+    /// Size of the bitten allocation.
+    pub size: usize,
+    /// Alignment the bitten allocation was requested with.
+    pub align: usize,
+    /// Offset of the eaten word within the allocation.
+    pub offset: usize,
+    pub mask: u64,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl BiteEvent {
+    /// How many bytes of the word or byte the bite actually changed.
+    pub fn bytes_changed(&self) -> usize {
+        let flipped = self.before ^ self.after;
+        flipped
+            .to_ne_bytes()
+            .iter()
+            .filter(|&&byte| byte != 0)
+            .count()
+    }
+
+    /// Index of the flipped bit within the eaten word or byte, counting from the least significant
+    /// one, when the bite flipped exactly one bit, e.g. with [`CorruptionKind::BitRot`].
+    pub fn bit(&self) -> Option<u32> {
+        let flipped = self.before ^ self.after;
+        flipped.is_power_of_two().then(|| flipped.trailing_zeros())
+    }
+}
+
+// One seqlock-protected record. `seq` is `2 * pos + 1` while position `pos` is being written and
+// `2 * pos + 2` once it is complete, so readers can tell torn and overwritten records apart.
+struct BiteRecord {
+    seq: AtomicUsize,
+    timestamp: AtomicU64,
+    bite: AtomicUsize,
+    kind: AtomicU8,
+    interval: AtomicU64,
+    rampage: AtomicBool,
+    text: AtomicBool,
+    pointer: AtomicBool,
+    addr: AtomicUsize,
+    size: AtomicUsize,
+    align: AtomicUsize,
+    offset: AtomicUsize,
+    mask: AtomicU64,
+    before: AtomicU64,
+    after: AtomicU64,
+}
+
+static BITE_LOG: [BiteRecord; BITE_LOG_LEN] = {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY_RECORD: BiteRecord = BiteRecord {
+        seq: AtomicUsize::new(0),
+        timestamp: AtomicU64::new(0),
+        bite: AtomicUsize::new(0),
+        kind: AtomicU8::new(0),
+        interval: AtomicU64::new(0),
+        rampage: AtomicBool::new(false),
+        text: AtomicBool::new(false),
+        pointer: AtomicBool::new(false),
+        addr: AtomicUsize::new(0),
+        size: AtomicUsize::new(0),
+        align: AtomicUsize::new(0),
+        offset: AtomicUsize::new(0),
+        mask: AtomicU64::new(0),
+        before: AtomicU64::new(0),
+        after: AtomicU64::new(0),
+    };
+    [EMPTY_RECORD; BITE_LOG_LEN]
+};
+static BITE_LOG_HEAD: AtomicUsize = AtomicUsize::new(0);
+
+fn record_bite(event: BiteEvent) {
+    let pos = BITE_LOG_HEAD.fetch_add(1, Ordering::AcqRel);
+    let record = &BITE_LOG[pos % BITE_LOG_LEN];
+    record.seq.store(2 * pos + 1, Ordering::Relaxed);
+    fence(Ordering::Release);
+    record
+        .timestamp
+        .store(event.timestamp.as_nanos() as u64, Ordering::Relaxed);
+    record.bite.store(event.bite, Ordering::Relaxed);
+    record.kind.store(event.kind as u8, Ordering::Relaxed);
+    record
+        .interval
+        .store(event.interval.as_nanos() as u64, Ordering::Relaxed);
+    record.rampage.store(event.rampage, Ordering::Relaxed);
+    record.text.store(event.text, Ordering::Relaxed);
+    record.pointer.store(event.pointer, Ordering::Relaxed);
+    record.addr.store(event.addr, Ordering::Relaxed);
+    record.size.store(event.size, Ordering::Relaxed);
+    record.align.store(event.align, Ordering::Relaxed);
+    record.offset.store(event.offset, Ordering::Relaxed);
+    record.mask.store(event.mask, Ordering::Relaxed);
+    record.before.store(event.before, Ordering::Relaxed);
+    record.after.store(event.after, Ordering::Relaxed);
+    record.seq.store(2 * pos + 2, Ordering::Release);
+}
+
+fn read_bite(pos: usize) -> Option<BiteEvent> {
+    let record = &BITE_LOG[pos % BITE_LOG_LEN];
+    let seq = 2 * pos + 2;
+    if record.seq.load(Ordering::Acquire) != seq {
+        return None;
+    }
+    let event = BiteEvent {
+        timestamp: Duration::from_nanos(record.timestamp.load(Ordering::Relaxed)),
+        bite: record.bite.load(Ordering::Relaxed),
+        kind: CorruptionKind::from_u8(record.kind.load(Ordering::Relaxed)),
+        interval: Duration::from_nanos(record.interval.load(Ordering::Relaxed)),
+        rampage: record.rampage.load(Ordering::Relaxed),
+        text: record.text.load(Ordering::Relaxed),
+        pointer: record.pointer.load(Ordering::Relaxed),
+        addr: record.addr.load(Ordering::Relaxed),
+        size: record.size.load(Ordering::Relaxed),
+        align: record.align.load(Ordering::Relaxed),
+        offset: record.offset.load(Ordering::Relaxed),
+        mask: record.mask.load(Ordering::Relaxed),
+        before: record.before.load(Ordering::Relaxed),
+        after: record.after.load(Ordering::Relaxed),
+    };
+    fence(Ordering::Acquire);
+    (record.seq.load(Ordering::Relaxed) == seq).then_some(event)
+}
+
+/// Up to `n` of the most recent bites, oldest first. Only the last 1024 bites are kept; records
+/// overwritten while being read are skipped.
+pub fn recent_bites(n: usize) -> Vec<BiteEvent> {
+    let head = BITE_LOG_HEAD.load(Ordering::Acquire);
+    let start = head.saturating_sub(n.min(BITE_LOG_LEN));
+    internally(|| (start..head).filter_map(read_bite).collect())
+}
+
+/// Hands every bite from now on to `sink`, on a thread of its own. Unlike hooks, the sink runs
+/// outside the bite, reading the bite log after the fact, so it may allocate, lock and log at
+/// will, e.g. `craturn::forward_bites(|bite| tracing::warn!(?bite, "craturn bite"))`. Whatever it
+/// logs through must not itself be bitten, so consider [`exempt_current_thread`] inside the sink.
+///
+/// A sink falling more than 1024 bites behind misses the overwritten ones.
+#[cfg(feature = "std")]
+pub fn forward_bites(mut sink: impl FnMut(&BiteEvent) + Send + 'static) {
+    let mut next = BITE_LOG_HEAD.load(Ordering::Acquire);
+    let drain = move || loop {
+        let head = BITE_LOG_HEAD.load(Ordering::Acquire);
+        next = next.max(head.saturating_sub(BITE_LOG_LEN));
+        while next < head {
+            match read_bite(next) {
+                Some(event) => sink(&event),
+                // Still being written, come back for it.
+                None if BITE_LOG[next % BITE_LOG_LEN].seq.load(Ordering::Acquire)
+                    < 2 * next + 2 =>
+                {
+                    break;
+                }
+                None => {}
+            }
+            next += 1;
+        }
+        if STOPPED.load(Ordering::SeqCst) && next == BITE_LOG_HEAD.load(Ordering::Acquire) {
+            break;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    internally(|| thread::spawn(drain));
+}
+
+/// Number of bites that fell out of the log because newer ones overwrote them.
+pub fn dropped_bites() -> usize {
+    BITE_LOG_HEAD
+        .load(Ordering::Relaxed)
+        .saturating_sub(BITE_LOG_LEN)
+}
+
+// === Trace ===
+
+static TRACING: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "std")]
+static TRACE: Mutex<Vec<TraceStep>> = Mutex::new(Vec::new());
+// The trace being replayed, and how far it got.
+#[cfg(feature = "std")]
+static REPLAYING: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "std")]
+static REPLAY: Mutex<(Vec<TraceStep>, usize)> = Mutex::new((Vec::new(), 0));
+// Ticks of the main eater so far, which times the steps of a trace.
+#[cfg(feature = "std")]
+static TRACE_CLOCK: AtomicU64 = AtomicU64::new(0);
+static REPLAY_DIVERGENCES: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "std")]
+const TRACE_MAGIC: &[u8] = b"CRT1";
+
+/// One eaten word of a trace.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+struct TraceStep {
+    tick: u64,
+    // The allocation, by the order it was tracked in since the process started.
+    serial: usize,
+    offset: usize,
+    mask: u64,
+    width: u8,
+    kind: CorruptionKind,
+}
+
+/// Why [`replay_trace`] refused a trace.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TraceError {
+    /// The bytes do not start like a trace from [`export_trace`].
+    NotATrace,
+    /// The trace breaks off in the middle of a step, or a step makes no sense.
+    Malformed,
+}
+
+impl core::fmt::Display for TraceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TraceError::NotATrace => write!(f, "not a craturn trace"),
+            TraceError::Malformed => write!(f, "malformed craturn trace"),
+        }
+    }
+}
+
+impl core::error::Error for TraceError {}
+
+/// Every word eaten since awakening with [`AllocatorBuilder::trace`], in a compact binary format
+/// for [`replay_trace`]. Empty without tracing.
+///
+/// Allocates the returned `Vec`, so it must not be called from bite hooks.
+#[cfg(feature = "std")]
+pub fn export_trace() -> Vec<u8> {
+    internally(|| {
+        let trace = TRACE.lock().unwrap_or_else(|e| e.into_inner());
+        let mut bytes = TRACE_MAGIC.to_vec();
+        let mut tick = 0;
+        for step in trace.iter() {
+            put_varint(&mut bytes, step.tick - tick);
+            put_varint(&mut bytes, step.serial as u64);
+            put_varint(&mut bytes, step.offset as u64);
+            put_varint(&mut bytes, step.mask);
+            bytes.extend([step.width, step.kind as u8]);
+            tick = step.tick;
+        }
+        bytes
+    })
+}
+
+/// Makes the creature take exactly the bites of a trace from [`export_trace`] instead of picking
+/// its own, for a reproducer of whatever a traced run broke. Each bite lands on the allocation
+/// tracked in the same order since the process started, on the same tick of the eater, so call
+/// this first thing in `main` and awaken the creature with the same settings. Loading the trace
+/// must not shift the order either: embed it with `include_bytes!`, or read it between
+/// [`exempt_current_thread`] and [`unexempt_current_thread`]. Bites of a kind
+/// that draws random numbers, like [`CorruptionKind::ByteShuffle`], may come out differently.
+///
+/// Allocations the program no longer makes, or that are gone or too small, are skipped and
+/// counted in [`Stats::replay_divergences`]. Once the trace is done, the creature stays quiet.
+/// Returns the number of words the trace will eat.
+#[cfg(feature = "std")]
+pub fn replay_trace(trace: &[u8]) -> Result<usize, TraceError> {
+    let mut bytes = trace
+        .strip_prefix(TRACE_MAGIC)
+        .ok_or(TraceError::NotATrace)?;
+    let steps = internally(|| {
+        let mut steps = Vec::new();
+        let mut tick = 0u64;
+        while !bytes.is_empty() {
+            let mut varint = || take_varint(&mut bytes).ok_or(TraceError::Malformed);
+            tick = tick.checked_add(varint()?).ok_or(TraceError::Malformed)?;
+            let serial = varint()? as usize;
+            let offset = varint()? as usize;
+            let mask = varint()?;
+            let (&[width, kind], rest) = bytes.split_first_chunk().ok_or(TraceError::Malformed)?;
+            if !matches!(width, 1 | 2 | 4 | 8) || kind > CorruptionKind::LastByte as u8 {
+                return Err(TraceError::Malformed);
+            }
+            bytes = rest;
+            steps.push(TraceStep {
+                tick,
+                serial,
+                offset,
+                mask,
+                width,
+                kind: CorruptionKind::from_u8(kind),
+            });
+        }
+        Ok(steps)
+    })?;
+    let len = steps.len();
+    *REPLAY.lock().unwrap_or_else(|e| e.into_inner()) = (steps, 0);
+    REPLAYING.store(true, Ordering::SeqCst);
+    Ok(len)
+}
+
+/// LEB128, seven bits at a time.
+#[cfg(feature = "std")]
+fn put_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+#[cfg(feature = "std")]
+fn take_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= ((byte & 0x7F) as u64).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Adds an eaten word to the trace, if tracing.
+#[cfg(feature = "std")]
+fn trace_bite(serial: usize, offset: usize, width: usize, mask: u64, kind: CorruptionKind) {
+    if !TRACING.load(Ordering::Relaxed) {
+        return;
+    }
+    internally(|| {
+        let mut trace = TRACE.lock().unwrap_or_else(|e| e.into_inner());
+        // Read under the lock, so bites taken by hand next to the eater stay in order.
+        trace.push(TraceStep {
+            tick: TRACE_CLOCK.load(Ordering::Relaxed),
+            serial,
+            offset,
+            mask,
+            width: width as u8,
+            kind,
+        });
+    });
+}
+
+/// Moves the trace clock on for a tick of the main eater and, while a trace is replayed, takes
+/// the bites due instead of the usual ones. `None` when the tick goes on as usual.
+#[cfg(feature = "std")]
+fn replay_tick() -> Option<usize> {
+    let clock = match extra_eater() {
+        None => TRACE_CLOCK.fetch_add(1, Ordering::Relaxed) + 1,
+        Some(_) => TRACE_CLOCK.load(Ordering::Relaxed),
+    };
+    if !REPLAYING.load(Ordering::SeqCst) {
+        return None;
+    }
+    let _in_flight = InFlight::new();
+    if extra_eater().is_some() || !hungry() {
+        return Some(0);
+    }
+    let mut replay = REPLAY.lock().unwrap_or_else(|e| e.into_inner());
+    let (steps, next) = &mut *replay;
+    let mut eaten = 0;
+    while let Some(step) = steps.get(*next).filter(|step| step.tick <= clock) {
+        eaten += replay_step(*step);
+        *next += 1;
+    }
+    if eaten > 0 {
+        EVENTS.fetch_add(1, Ordering::Relaxed);
+        BITES.fetch_add(1, Ordering::Relaxed);
+        WORDS_FLIPPED.fetch_add(eaten, Ordering::Relaxed);
+    }
+    Some(eaten)
+}
+
+/// Eats the word of `step`, or counts a divergence when its allocation is not there.
+#[cfg(feature = "std")]
+fn replay_step(step: TraceStep) -> usize {
+    let width = step.width as usize;
+    let slot = (0..ACTIVE_LEN.load(Ordering::Acquire))
+        .map(|pos| active(pos).load(Ordering::Acquire))
+        .find(|&slot| {
+            slot != EMPTY && registry(slot).serial.load(Ordering::Relaxed) == step.serial
+        });
+    let Some(pinned) = slot.and_then(Pinned::new) else {
+        REPLAY_DIVERGENCES.fetch_add(1, Ordering::Relaxed);
+        return 0;
+    };
+    let slot = pinned.slot;
+    let addr = registry(slot).addr.load(Ordering::Acquire);
+    let size = registry(slot).size.load(Ordering::Relaxed);
+    let same = addr != 0
+        && registry(slot).serial.load(Ordering::Relaxed) == step.serial
+        && step
+            .offset
+            .checked_add(width)
+            .is_some_and(|end| end <= size);
+    let mut eaten = 0;
+    if same {
+        let prey = Prey {
+            bite: NEXT_BITE.fetch_add(1, Ordering::Relaxed),
+            addr,
+            size,
+            align: 1 << registry(slot).align_shift.load(Ordering::Relaxed),
+            serial: step.serial,
+            text: false,
+        };
+        if let Some(event) = unsafe { eat(prey, step.offset, width, step.mask, step.kind) } {
+            BYTES_FLIPPED.fetch_add(event.bytes_changed(), Ordering::Relaxed);
+            if registry(slot).bites.fetch_add(1, Ordering::Relaxed) == 0 {
+                ALLOCATIONS_HIT.fetch_add(1, Ordering::Relaxed);
+                NEVER_BITTEN.fetch_sub(1, Ordering::Relaxed);
+            }
+            eaten = 1;
+        }
+    } else {
+        REPLAY_DIVERGENCES.fetch_add(1, Ordering::Relaxed);
+    }
+    eaten
+}
+
+// === Bite callback ===
+
+/// Called with `(addr, offset, old, new)` right after a word has been eaten.
+pub type BiteCallback = fn(usize, usize, u64, u64);
+
+static BITE_CALLBACK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+#[inline(always)]
+fn bite_callback() -> Option<BiteCallback> {
+    let callback = BITE_CALLBACK.load(Ordering::Acquire);
+    // SAFETY: the only non-null values ever stored are `BiteCallback` pointers.
+    (!callback.is_null())
+        .then(|| unsafe { core::mem::transmute::<*mut (), BiteCallback>(callback) })
+}
+
+// === Bite hooks ===
+
+// Hooks need `std` for their lock. Without it, stick to [`Allocator::on_bite`].
+
+#[cfg(feature = "std")]
+type AfterBiteHook = Box<dyn Fn(&BiteEvent) + Send + Sync>;
+#[cfg(feature = "std")]
+type BeforeBiteHook = Box<dyn Fn(&BiteEvent) -> bool + Send + Sync>;
+
+// Readers are in-flight bites, so taking the write lock waits for them to finish.
+#[cfg(feature = "std")]
+static AFTER_BITE_HOOK: RwLock<Option<AfterBiteHook>> = RwLock::new(None);
+#[cfg(feature = "std")]
+static BEFORE_BITE_HOOK: RwLock<Option<BeforeBiteHook>> = RwLock::new(None);
+
+/// Registers a hook called on the eater thread right after a word has been eaten, replacing any
+/// previous one. Unlike [`Allocator::on_bite`], the hook may allocate. It must not register or
+/// clear hooks itself, nor free the bitten allocation, which stays pinned until the hook returns.
+#[cfg(feature = "std")]
+pub fn on_bite(hook: impl Fn(&BiteEvent) + Send + Sync + 'static) {
+    let hook: AfterBiteHook = internally(|| Box::new(hook));
+    *AFTER_BITE_HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(hook);
+}
+
+/// Registers a hook called right before a word is eaten, with `after` holding the value about to
+/// be written. Returning `false` spares the word.
+#[cfg(feature = "std")]
+pub fn before_bite(hook: impl Fn(&BiteEvent) -> bool + Send + Sync + 'static) {
+    let hook: BeforeBiteHook = internally(|| Box::new(hook));
+    *BEFORE_BITE_HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(hook);
+}
+
+/// Removes the hooks registered with [`on_bite`] and [`before_bite`]. Once this returns, neither
+/// hook runs again, even for a bite that was already in progress.
+#[cfg(feature = "std")]
+pub fn clear_bite_hook() {
+    *AFTER_BITE_HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+    *BEFORE_BITE_HOOK.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+#[cfg(feature = "std")]
+fn allowed_by_hook(event: &BiteEvent) -> bool {
+    let hook = BEFORE_BITE_HOOK.read().unwrap_or_else(|e| e.into_inner());
+    hook.as_ref().is_none_or(|hook| hook(event))
+}
+
+#[cfg(feature = "std")]
+fn notify_hook(event: &BiteEvent) {
+    let hook = AFTER_BITE_HOOK.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(hook) = hook.as_ref() {
+        hook(event);
+    }
+}
+
+#[cfg(not(feature = "std"))]
+fn allowed_by_hook(_: &BiteEvent) -> bool {
+    true
+}
+
+#[cfg(not(feature = "std"))]
+fn notify_hook(_: &BiteEvent) {}
+
+// === Healing ===
+
+// Eaten words waiting to be restored, see `AllocatorBuilder::heal_after`. An entry is claimed by
+// moving `state` away from `HEAL_FREE` and released by moving it back.
+#[cfg(feature = "std")]
+const MAX_HEALS: usize = 256;
+#[cfg(feature = "std")]
+const HEAL_FREE: u8 = 0;
+#[cfg(feature = "std")]
+const HEAL_BUSY: u8 = 1;
+#[cfg(feature = "std")]
+const HEAL_PENDING: u8 = 2;
+
+#[cfg(feature = "std")]
+struct Heal {
+    state: AtomicU8,
+    slot: AtomicUsize,
+    generation: AtomicUsize,
+    addr: AtomicUsize,
+    offset: AtomicUsize,
+    width: AtomicUsize,
+    before: AtomicU64,
+    due_ms: AtomicU64,
+}
+
+#[cfg(feature = "std")]
+static HEALS: [Heal; MAX_HEALS] = {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY_HEAL: Heal = Heal {
+        state: AtomicU8::new(HEAL_FREE),
+        slot: AtomicUsize::new(0),
+        generation: AtomicUsize::new(0),
+        addr: AtomicUsize::new(0),
+        offset: AtomicUsize::new(0),
+        width: AtomicUsize::new(0),
+        before: AtomicU64::new(0),
+        due_ms: AtomicU64::new(0),
+    };
+    [EMPTY_HEAL; MAX_HEALS]
+};
+
+// Zero when healing is off.
+#[cfg(feature = "std")]
+static HEAL_AFTER_MS: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "std")]
+#[inline(always)]
+fn healing() -> bool {
+    HEAL_AFTER_MS.load(Ordering::Relaxed) != 0
+}
+
+#[cfg(feature = "std")]
+fn heal_room() -> usize {
+    HEALS
+        .iter()
+        .filter(|heal| heal.state.load(Ordering::Relaxed) == HEAL_FREE)
+        .count()
+}
+
+/// Remembers to restore the word `event` ate from the pinned `slot`. Returns false if there is
+/// no room left, in which case the caller must restore it right away.
+#[cfg(feature = "std")]
+fn schedule_heal(slot: usize, generation: usize, event: &BiteEvent, width: usize) -> bool {
+    // A word eaten twice heals back to what it was before the first bite.
+    let already_pending = HEALS.iter().any(|heal| {
+        heal.state.load(Ordering::Acquire) == HEAL_PENDING
+            && heal.addr.load(Ordering::Relaxed) == event.addr
+            && heal.offset.load(Ordering::Relaxed) == event.offset
+            && heal.slot.load(Ordering::Relaxed) == slot
+    });
+    if already_pending {
+        return true;
+    }
+    let Some(heal) = HEALS.iter().find(|heal| {
+        heal.state
+            .compare_exchange(HEAL_FREE, HEAL_BUSY, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }) else {
+        return false;
+    };
+    heal.slot.store(slot, Ordering::Relaxed);
+    heal.generation.store(generation, Ordering::Relaxed);
+    heal.addr.store(event.addr, Ordering::Relaxed);
+    heal.offset.store(event.offset, Ordering::Relaxed);
+    heal.width.store(width, Ordering::Relaxed);
+    heal.before.store(event.before, Ordering::Relaxed);
+    let due = now_ms().saturating_add(HEAL_AFTER_MS.load(Ordering::Relaxed));
+    heal.due_ms.store(due, Ordering::Relaxed);
+    heal.state.store(HEAL_PENDING, Ordering::Release);
+    true
+}
+
+/// Restores every eaten word due by `now`. Words whose allocation was freed, moved or shrunk past
+/// them in the meantime are forgotten.
+#[cfg(feature = "std")]
+fn heal_due(now: u64) {
+    for heal in &HEALS {
+        if heal.state.load(Ordering::Acquire) != HEAL_PENDING
+            || heal.due_ms.load(Ordering::Relaxed) > now
+            || heal
+                .state
+                .compare_exchange(
+                    HEAL_PENDING,
+                    HEAL_BUSY,
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                )
+                .is_err()
+        {
+            continue;
+        }
+        let slot = heal.slot.load(Ordering::Relaxed);
+        let Some(generation) = pin(slot) else {
+            // Mid-bite on another thread, try again next time.
+            heal.state.store(HEAL_PENDING, Ordering::Release);
+            continue;
+        };
+        let addr = heal.addr.load(Ordering::Relaxed);
+        let offset = heal.offset.load(Ordering::Relaxed);
+        let width = heal.width.load(Ordering::Relaxed);
+        // `realloc` may have shrunk the block in place since, leaving the word past its end.
+        if generation == heal.generation.load(Ordering::Relaxed)
+            && registry(slot).addr.load(Ordering::Acquire) == addr
+            && offset + width <= registry(slot).size.load(Ordering::Relaxed)
+        {
+            unsafe { restore(addr + offset, width, heal.before.load(Ordering::Relaxed)) };
+        }
+        unpin(slot, generation);
+        heal.state.store(HEAL_FREE, Ordering::Release);
+    }
+}
+
+/// Restores what is due for bites taken by hand, which no eater thread may be around to heal.
+#[cfg(feature = "std")]
+fn heal_now() {
+    if healing() {
+        heal_due(now_ms());
+    }
+}
+
+#[cfg(feature = "std")]
+unsafe fn restore(word: usize, width: usize, value: u64) {
+    write_word(word, width, value);
+}
+
+// === Randomness ===
+
+static SEED: AtomicU64 = AtomicU64::new(0);
+static RNG: AtomicU64 = AtomicU64::new(0);
+
+/// SplitMix64 over a shared atomic state, so every caller draws a distinct value and a single
+/// eater replays the same sequence for the same seed. Extra eaters draw from their own state.
+#[inline(always)]
+fn next_random() -> u64 {
+    #[cfg(feature = "std")]
+    let rng = extra_eater().map_or(&RNG, |eater| &eater.rng);
+    #[cfg(not(feature = "std"))]
+    let rng = &RNG;
+    let mut z = rng
+        .fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+        .wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Uniform-enough value in `0..bound`. `bound` must be non-zero.
+#[inline(always)]
+fn random_below(bound: usize) -> usize {
+    (next_random() % bound as u64) as usize
+}
+
+/// Turns a probability into a threshold for [`roll`]. Probabilities outside `0.0..=1.0` clamp.
+const fn chance(probability: f64) -> u64 {
+    if probability >= 1.0 {
+        u64::MAX
+    } else if probability > 0.0 {
+        (probability * u64::MAX as f64) as u64
+    } else {
+        0
+    }
+}
+
+/// True with the probability `threshold` was made from by [`chance`].
+#[inline(always)]
+fn roll(threshold: u64) -> bool {
+    threshold == u64::MAX || (threshold != 0 && next_random() < threshold)
+}
+
+#[cfg(feature = "std")]
+fn entropy_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let stack = &nanos as *const u64 as u64;
+    nanos ^ stack.rotate_left(32)
+}
+
+// Only the stack address to go on without `std`, which is often fixed on embedded targets. Seed
+// explicitly there.
+#[cfg(not(feature = "std"))]
+fn entropy_seed() -> u64 {
+    let marker = 0u64;
+    (&marker as *const u64 as u64).rotate_left(32) ^ 0x9E37_79B9_7F4A_7C15
+}
+
+/// The seed driving the eater, or `None` if the creature has not been awakened yet. Pass it back
+/// via [`AllocatorBuilder::seed`] to replay the same sequence of bites.
+pub fn current_seed() -> Option<u64> {
+    EATER_STARTED
+        .load(Ordering::Acquire)
+        .then(|| SEED.load(Ordering::Relaxed))
+}
+
+// === Environment ===
+
+// The first bite as configured, for `effective_config`.
+static FIRST_BITE_MS: AtomicU64 = AtomicU64::new(0);
+// Whether the environment was read yet: not, being read, or done.
+#[cfg(feature = "std")]
+static ENV_READ: AtomicU8 = AtomicU8::new(0);
+
+/// Lets `CRATURN_HUNGER`, `CRATURN_SEED`, `CRATURN_MIN_SIZE` and `CRATURN_FIRST_BITE_MS` override
+/// what the program was built with, once, after the file `CRATURN_CONFIG` points at with the
+/// `config` feature. Reading the environment allocates, so it is left to the eater thread, the
+/// first bite taken by hand, or whoever asks for the [`effective_config`] first. Values that don't
+/// parse are reported on stderr and ignored.
+#[cfg(feature = "std")]
+fn read_env_once() {
+    if ENV_READ
+        .compare_exchange(0, 1, Ordering::AcqRel, Ordering::Acquire)
+        .is_err()
+    {
+        while ENV_READ.load(Ordering::Acquire) != 2 {
+            thread::yield_now();
+        }
+        return;
+    }
+    internally(|| {
+        #[cfg(feature = "config")]
+        if let Some(path) = std::env::var_os("CRATURN_CONFIG") {
+            if let Err(err) = configure_from_file(&path) {
+                eprintln!("craturn: ignoring CRATURN_CONFIG={path:?}: {err}");
+            }
+        }
+        if let Some(hunger) = env_var("CRATURN_HUNGER") {
+            install_hunger(hunger);
+        }
+        if let Some(seed) = env_var("CRATURN_SEED") {
+            SEED.store(seed, Ordering::Relaxed);
+            RNG.store(seed, Ordering::Relaxed);
+        }
+        if let Some(min_size) = env_var("CRATURN_MIN_SIZE") {
+            MIN_SIZE.store(min_size, Ordering::Relaxed);
+        }
+        if let Some(first_bite) = env_var("CRATURN_FIRST_BITE_MS") {
+            FIRST_BITE_MS.store(first_bite, Ordering::Relaxed);
+        }
+    });
+    ENV_READ.store(2, Ordering::Release);
+}
+
+#[cfg(feature = "std")]
+fn env_var<T: core::str::FromStr>(name: &str) -> Option<T> {
+    let value = std::env::var_os(name)?;
+    let parsed = value.to_str().and_then(|value| value.trim().parse().ok());
+    if parsed.is_none() {
+        eprintln!("craturn: ignoring {name}={value:?}, keeping the built-in setting");
+    }
+    parsed
+}
+
+/// The settings the creature actually runs with, once the environment had its say.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EffectiveConfig {
+    pub hunger: Hunger,
+    /// `None` before awakening.
+    pub seed: Option<u64>,
+    pub min_size: usize,
+    pub bite: BiteConfig,
+}
+
+/// What the creature runs with right now, `CRATURN_*` variables included, e.g. to log at startup.
+pub fn effective_config() -> EffectiveConfig {
+    #[cfg(feature = "std")]
+    if EATER_STARTED.load(Ordering::Acquire) {
+        read_env_once();
+    }
+    EffectiveConfig {
+        hunger: hunger(),
+        seed: current_seed(),
+        min_size: MIN_SIZE.load(Ordering::Relaxed),
+        bite: BiteConfig {
+            first_bite: Duration::from_millis(FIRST_BITE_MS.load(Ordering::Relaxed)),
+            interval: Duration::from_millis(INTERVAL_MS.load(Ordering::Relaxed)),
+            words: WORDS.load(Ordering::Relaxed),
+            mask: MASK.load(Ordering::Relaxed),
+            slots_per_bite: SLOTS_PER_BITE.load(Ordering::Relaxed),
+        },
+    }
+}
+
+// === Config file ===
+
+/// A checked-in description of a chaos experiment, loaded with [`configure_from_file`] or through
+/// `CRATURN_CONFIG`. Every field is optional and only the ones given are applied, over whatever the
+/// program was built with.
+///
+/// Written and read as a flat subset of TOML: `key = value` lines with strings, integers, floats,
+/// booleans and arrays, and `#` comments. Durations are in milliseconds and names of presets and
+/// kinds are as in the code, in any case:
+///
+/// ```toml
+/// hunger = "Hungry"
+/// schedule = [[0, "Hungry"], [30000, "Starving"]]
+/// seed = 42
+/// min_size = 64
+/// size_filter = [64, 4096]
+/// kind = "BitRot"
+/// bite_budget = 1000
+/// probability = 0.5
+/// ```
+#[cfg(feature = "config")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    pub hunger: Option<Hunger>,
+    /// `(after, hunger)` phases, see [`set_schedule`].
+    pub schedule: Option<Vec<(Duration, Hunger)>>,
+    pub seed: Option<u64>,
+    pub min_size: Option<usize>,
+    /// Smallest and largest allocation bitten, see [`set_size_filter`].
+    pub size_filter: Option<(usize, usize)>,
+    pub kind: Option<CorruptionKind>,
+    pub bite_budget: Option<u64>,
+    pub probability: Option<f64>,
+}
+
+/// Why a [`Config`] could not be loaded. Lines are counted from one.
+#[cfg(feature = "config")]
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    /// Not a `key = value` line, or a value that doesn't parse.
+    Syntax {
+        line: usize,
+    },
+    /// A key `Config` knows nothing about, or one given twice.
+    UnknownKey {
+        line: usize,
+        key: String,
+    },
+    /// A known key with a value of the wrong type or out of range.
+    BadValue {
+        line: usize,
+        key: String,
+    },
+}
+
+#[cfg(feature = "config")]
+impl core::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "cannot read craturn config: {err}"),
+            ConfigError::Syntax { line } => write!(f, "craturn config, line {line}: syntax error"),
+            ConfigError::UnknownKey { line, key } => {
+                write!(
+                    f,
+                    "craturn config, line {line}: unknown or repeated key `{key}`"
+                )
+            }
+            ConfigError::BadValue { line, key } => {
+                write!(f, "craturn config, line {line}: bad value for `{key}`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl std::error::Error for ConfigError {}
+
+#[cfg(feature = "config")]
+#[derive(Clone, Debug, PartialEq)]
+enum ConfigValue {
+    Int(u64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Array(Vec<ConfigValue>),
+}
+
+#[cfg(feature = "config")]
+impl ConfigValue {
+    fn int(&self) -> Option<u64> {
+        match *self {
+            ConfigValue::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn size(&self) -> Option<usize> {
+        self.int()?.try_into().ok()
+    }
+
+    fn float(&self) -> Option<f64> {
+        match *self {
+            ConfigValue::Float(value) => Some(value),
+            ConfigValue::Int(value) => Some(value as f64),
+            _ => None,
+        }
+    }
+
+    fn hunger(&self) -> Option<Hunger> {
+        match self {
+            ConfigValue::Str(name) => name.parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn kind(&self) -> Option<CorruptionKind> {
+        let ConfigValue::Str(name) = self else {
+            return None;
+        };
+        (0..=CorruptionKind::LastByte as u8)
+            .map(CorruptionKind::from_u8)
+            .find(|kind| format!("{kind:?}").eq_ignore_ascii_case(name))
+    }
+
+    fn pair(&self) -> Option<(&ConfigValue, &ConfigValue)> {
+        match self {
+            ConfigValue::Array(items) if items.len() == 2 => Some((&items[0], &items[1])),
+            _ => None,
+        }
+    }
+}
+
+/// Reads one value off the front of `text`, returning it with the rest.
+#[cfg(feature = "config")]
+fn parse_value(text: &str) -> Option<(ConfigValue, &str)> {
+    let text = text.trim_start();
+    if let Some(mut rest) = text.strip_prefix('[') {
+        let mut items = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Some((ConfigValue::Array(items), rest));
+            }
+            let (item, after) = parse_value(rest)?;
+            items.push(item);
+            rest = after.trim_start();
+            match rest.strip_prefix(',') {
+                Some(after) => rest = after,
+                None if rest.starts_with(']') => {}
+                None => return None,
+            }
+        }
+    }
+    if let Some(mut rest) = text.strip_prefix('"') {
+        let mut string = String::new();
+        loop {
+            let mut chars = rest.chars();
+            match chars.next()? {
+                '"' => return Some((ConfigValue::Str(string), chars.as_str())),
+                '\\' => string.push(match chars.next()? {
+                    'n' => '\n',
+                    't' => '\t',
+                    c @ ('"' | '\\') => c,
+                    _ => return None,
+                }),
+                c => string.push(c),
+            }
+            rest = chars.as_str();
+        }
+    }
+    let end = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '+' | '-')))
+        .unwrap_or(text.len());
+    let (token, rest) = text.split_at(end);
+    let value = match token {
+        "true" => ConfigValue::Bool(true),
+        "false" => ConfigValue::Bool(false),
+        _ => {
+            let digits = token.replace('_', "");
+            if let Some(hex) = digits.strip_prefix("0x") {
+                ConfigValue::Int(u64::from_str_radix(hex, 16).ok()?)
+            } else if let Ok(int) = digits.parse() {
+                ConfigValue::Int(int)
+            } else {
+                ConfigValue::Float(digits.parse().ok()?)
+            }
+        }
+    };
+    Some((value, rest))
+}
+
+/// Drops a `#` comment from the end of a line, unless it is inside a string.
+#[cfg(feature = "config")]
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+#[cfg(feature = "config")]
+impl core::str::FromStr for Config {
+    type Err = ConfigError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut config = Config::default();
+        let mut seen: Vec<String> = Vec::new();
+        let mut lines = text.lines().enumerate();
+        while let Some((index, line)) = lines.next() {
+            let line_no = index + 1;
+            let mut line = strip_comment(line).trim().to_owned();
+            if line.is_empty() {
+                continue;
+            }
+            // Arrays may go on over several lines, until their brackets close.
+            while line.matches('[').count() > line.matches(']').count() {
+                let (_, more) = lines.next().ok_or(ConfigError::Syntax { line: line_no })?;
+                line.push(' ');
+                line.push_str(strip_comment(more).trim());
+            }
+            let syntax = ConfigError::Syntax { line: line_no };
+            let (key, value) = line.split_once('=').ok_or(syntax)?;
+            let key = key.trim();
+            let (value, rest) = parse_value(value).ok_or(ConfigError::Syntax { line: line_no })?;
+            if !rest.trim().is_empty() {
+                return Err(ConfigError::Syntax { line: line_no });
+            }
+            if seen.iter().any(|k| k == key) {
+                return Err(ConfigError::UnknownKey {
+                    line: line_no,
+                    key: key.to_owned(),
+                });
+            }
+            seen.push(key.to_owned());
+            let bad = || ConfigError::BadValue {
+                line: line_no,
+                key: key.to_owned(),
+            };
+            match key {
+                "hunger" => config.hunger = Some(value.hunger().ok_or_else(bad)?),
+                "schedule" => {
+                    let ConfigValue::Array(phases) = &value else {
+                        return Err(bad());
+                    };
+                    let phases = phases.iter().map(|phase| {
+                        let (after, hunger) = phase.pair()?;
+                        Some((Duration::from_millis(after.int()?), hunger.hunger()?))
+                    });
+                    config.schedule = Some(phases.collect::<Option<_>>().ok_or_else(bad)?);
+                }
+                "seed" => config.seed = Some(value.int().ok_or_else(bad)?),
+                "min_size" => config.min_size = Some(value.size().ok_or_else(bad)?),
+                "size_filter" => {
+                    let (min, max) = value.pair().ok_or_else(bad)?;
+                    let filter = (min.size().ok_or_else(bad)?, max.size().ok_or_else(bad)?);
+                    config.size_filter = Some(filter);
+                }
+                "kind" => config.kind = Some(value.kind().ok_or_else(bad)?),
+                "bite_budget" => config.bite_budget = Some(value.int().ok_or_else(bad)?),
+                "probability" => {
+                    let probability = value.float().filter(|p| (0.0..=1.0).contains(p));
+                    config.probability = Some(probability.ok_or_else(bad)?);
+                }
+                _ => {
+                    return Err(ConfigError::UnknownKey {
+                        line: line_no,
+                        key: key.to_owned(),
+                    })
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Writes the config back out in the format [`Config`] reads, one line per field given.
+#[cfg(feature = "config")]
+impl core::fmt::Display for Config {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(hunger) = self.hunger {
+            writeln!(f, "hunger = \"{hunger:?}\"")?;
+        }
+        if let Some(schedule) = &self.schedule {
+            write!(f, "schedule = [")?;
+            for (i, (after, hunger)) in schedule.iter().enumerate() {
+                let sep = if i == 0 { "" } else { ", " };
+                write!(f, "{sep}[{}, \"{hunger:?}\"]", after.as_millis())?;
+            }
+            writeln!(f, "]")?;
+        }
+        if let Some(seed) = self.seed {
+            writeln!(f, "seed = {seed}")?;
+        }
+        if let Some(min_size) = self.min_size {
+            writeln!(f, "min_size = {min_size}")?;
+        }
+        if let Some((min, max)) = self.size_filter {
+            writeln!(f, "size_filter = [{min}, {max}]")?;
+        }
+        if let Some(kind) = self.kind {
+            writeln!(f, "kind = \"{kind:?}\"")?;
+        }
+        if let Some(budget) = self.bite_budget {
+            writeln!(f, "bite_budget = {budget}")?;
+        }
+        if let Some(probability) = self.probability {
+            writeln!(f, "probability = {probability:?}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "config")]
+impl Config {
+    /// Applies every field given to the running creature, leaving the others as they are. The
+    /// hunger given is where the schedule starts from, not pinned like with [`set_hunger`]. The
+    /// schedule is leaked, as [`set_schedule`] needs it for good.
+    pub fn apply(&self) {
+        if let Some(hunger) = self.hunger {
+            install_hunger(hunger);
+        }
+        if let Some(schedule) = &self.schedule {
+            set_schedule(Box::leak(schedule.clone().into_boxed_slice()));
+        }
+        if let Some(seed) = self.seed {
+            SEED.store(seed, Ordering::Relaxed);
+            RNG.store(seed, Ordering::Relaxed);
+        }
+        if let Some(min_size) = self.min_size {
+            set_min_size(min_size);
+        }
+        if let Some((min, max)) = self.size_filter {
+            set_size_filter(min, max);
+        }
+        if let Some(kind) = self.kind {
+            set_kind(kind);
+        }
+        if let Some(budget) = self.bite_budget {
+            set_bite_budget(budget);
+        }
+        if let Some(probability) = self.probability {
+            set_probability(probability);
+        }
+        wake_eater();
+    }
+}
+
+/// Loads a [`Config`] from the file at `path`, applies it and echoes it to stderr, so every run
+/// says what it was made of. Unknown keys are an error and nothing is applied then. Needs the
+/// `config` feature.
+#[cfg(feature = "config")]
+pub fn configure_from_file(path: impl AsRef<std::path::Path>) -> Result<Config, ConfigError> {
+    internally(|| {
+        let path = path.as_ref();
+        let config: Config = std::fs::read_to_string(path)
+            .map_err(ConfigError::Io)?
+            .parse()?;
+        config.apply();
+        eprint!("craturn: loaded {}\n{config}", path.display());
+        Ok(config)
+    })
+}
+
+// === Allocator ===
+
+/// Stand-in for `std::alloc::System` when built without `std`. It cannot allocate anything, so
+/// give the creature a real allocator with [`AllocatorBuilder::inner`].
+#[cfg(not(feature = "std"))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct System;
+
+/// The creature. Wraps an inner allocator (the system one by default) which does the actual
+/// allocating, while the creature keeps track of the blocks and eats them.
+#[derive(Clone, Copy, Debug)]
+pub struct Allocator<A = System> {
+    pub hunger: Hunger,
+    pub first_bite: Duration,
+    pub interval: Duration,
+    pub poisson: bool,
+    pub jitter: f64,
+    pub words: usize,
+    pub mask: u64,
+    pub masks: &'static [u64],
+    pub slots_per_bite: usize,
+    pub kind: CorruptionKind,
+    pub filter: CorruptionFilter,
+    pub offset: OffsetStrategy,
+    pub scatter: bool,
+    pub soft: bool,
+    pub cruel: bool,
+    pub lane_aligned: bool,
+    pub dry_run: bool,
+    pub trace: bool,
+    pub blast_radius: usize,
+    pub granularity: usize,
+    pub sentinel: u64,
+    pub poison_on_free: Option<u64>,
+    pub victim: VictimPolicy,
+    pub fixate: bool,
+    pub once_per_allocation: bool,
+    pub seed: Option<u64>,
+    pub max_bites: Option<usize>,
+    pub warmup_allocs: usize,
+    pub stop_allocs: usize,
+    pub min_age: Duration,
+    pub max_age: Option<Duration>,
+    pub escalation: &'static [(Duration, Hunger)],
+    pub min_size: usize,
+    pub max_size: Option<usize>,
+    pub dirty_zeroed: f64,
+    pub probability: f64,
+    pub heal_after: Option<Duration>,
+    pub only_tag: Option<&'static str>,
+    pub manual: bool,
+    pub inner: A,
+}
+
+impl Allocator {
+    pub const fn builder() -> AllocatorBuilder {
+        AllocatorBuilder::new()
+    }
+
+    /// The creature at a preset, with everything else at its default. Every field is public, so
+    /// the rest can be set right in the static with
+    /// `Allocator { min_size: 128, ..Allocator::new(Hunger::Starving) }`, or with the
+    /// [`builder`](Self::builder).
+    pub const fn new(hunger: Hunger) -> Self {
+        AllocatorBuilder::new().hunger(hunger).build()
+    }
+
+    /// The creature with a hand-tuned appetite instead of a preset.
+    pub const fn custom(config: BiteConfig) -> Self {
+        AllocatorBuilder::new().config(config).build()
+    }
+
+    /// Registers `callback` to be called after every eaten word, replacing any previous one. Works
+    /// before and after [`awaken!`].
+    ///
+    /// The callback runs on the eater thread while it is in the middle of a bite, so it must not
+    /// allocate, block, or panic. Stick to atomics and raw writes.
+    pub fn on_bite(callback: BiteCallback) {
+        BITE_CALLBACK.store(callback as *mut (), Ordering::Release);
+    }
+
+    /// Stops the eater from biting until [`Allocator::resume`] is called. The eater keeps ticking
+    /// at its usual interval, it just leaves memory alone. A bite already in flight lands before
+    /// this returns.
+    pub fn pause() {
+        PAUSED.store(true, Ordering::SeqCst);
+        settle();
+    }
+
+    pub fn resume() {
+        PAUSED.store(false, Ordering::Release);
+    }
+
+    /// Whether [`Allocator::pause`] is in effect.
+    pub fn is_paused() -> bool {
+        PAUSED.load(Ordering::SeqCst)
+    }
+
+    /// Whether the creature has been awakened and not [stopped](Allocator::stop) since, i.e.
+    /// whether its eater is around. Unlike [`is_awake`], hibernation and pauses don't count.
+    /// Never awakens the creature itself.
+    pub fn is_active() -> bool {
+        EATER_STARTED.load(Ordering::Acquire) && !STOPPED.load(Ordering::SeqCst)
+    }
+
+    /// Puts the eater, and every extra one, down for good and waits for the main eater's thread
+    /// to exit and for any bite in flight to land. Once this returns, no further memory is
+    /// corrupted, neither by bites nor by dirty zeroed allocations, and every word still waiting
+    /// to [heal](AllocatorBuilder::heal_after) is restored.
+    ///
+    /// Called from a bite hook, the hook's own bite is still finished before the eater exits.
+    pub fn stop() {
+        STOPPED.store(true, Ordering::SeqCst);
+        wake_eater();
+        settle();
+        #[cfg(feature = "std")]
+        {
+            Self::join_eater();
+            heal_due(u64::MAX);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn join_eater() {
+        let handle = EATER_HANDLE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        if let Some(handle) = handle {
+            if handle.thread().id() != thread::current().id() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+impl<A> Allocator<A> {
+    /// Itself, for [`awaken!`] given an expression.
+    pub const fn into_allocator(self) -> Self {
+        self
+    }
+
+    /// Same as [`stats`].
+    pub fn stats(&self) -> Stats {
+        stats()
+    }
+
+    /// Same as [`tracked`].
+    pub fn tracked(&self) -> Vec<(usize, usize)> {
+        tracked()
+    }
+
+    /// Takes a single bite right now, returning how many words were eaten. Counts as a tick of the
+    /// eater, so it respects [`Allocator::pause`], [`Allocator::stop`], the bite budget and the
+    /// [`probability`](AllocatorBuilder::probability), but not the interval.
+    ///
+    /// Without `std` there is no eater thread, so this is the only way the creature eats. Call it
+    /// from a timer or the main loop.
+    pub fn do_one_bite(&self) -> usize {
+        self.start_eater_once();
+        // Without an eater thread, somebody has to.
+        #[cfg(feature = "std")]
+        {
+            read_env_once();
+            heal_now();
+        }
+        tick(1)
+    }
+
+    /// Like [`bite_now`], awakening the creature first if needed, and telling just where the bite
+    /// landed, as the address of the allocation and the offset into it.
+    pub fn bite_now(&self) -> Option<(usize, usize)> {
+        self.start_eater_once();
+        bite_now().map(|event| (event.addr, event.offset))
+    }
+
+    /// Spawns one more eater, biting next to the main one with the interval, shape and
+    /// `slots_per_bite` of `config`. The kind of bite, offsets, size limits and the bite budget
+    /// are shared, and so are pauses and [`Allocator::stop`]. Fixed schedules like
+    /// [`escalate`](AllocatorBuilder::escalate) only drive the main eater.
+    ///
+    /// Returns `None` when eight extra eaters are already running, or with the `disabled`
+    /// feature.
+    #[cfg(feature = "std")]
+    pub fn spawn_eater(&self, config: BiteConfig) -> Option<EaterHandle> {
+        if cfg!(feature = "disabled") {
+            return None;
+        }
+        self.start_eater_once();
+        let index = EXTRA_EATERS.iter().position(|eater| {
+            eater
+                .in_use
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+        })?;
+        let eater = &EXTRA_EATERS[index];
+        eater.stopped.store(false, Ordering::SeqCst);
+        eater
+            .interval_ms
+            .store(millis(config.interval), Ordering::Relaxed);
+        eater.words.store(config.words, Ordering::Relaxed);
+        eater.mask.store(config.mask, Ordering::Relaxed);
+        eater
+            .slots_per_bite
+            .store(config.slots_per_bite, Ordering::Relaxed);
+        eater
+            .victim
+            .store(VictimPolicy::Random as u8, Ordering::Relaxed);
+        eater.rng.store(next_random(), Ordering::Relaxed);
+        eater.bites.store(0, Ordering::Relaxed);
+        let first_bite = config.first_bite;
+        let thread = internally(|| {
+            let thread = thread::spawn(move || extra_eater_loop(eater, first_bite));
+            EXTRA_THREADS.lock().unwrap_or_else(|e| e.into_inner())[index] =
+                Some(thread.thread().clone());
+            thread
+        });
+        Some(EaterHandle { index, thread })
+    }
+
+    /// Installs the appetite on the first call and, with `std`, spawns the eater thread.
+    fn start_eater_once(&self) {
+        if cfg!(feature = "disabled") {
+            return;
+        }
+        if EATER_STARTED
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            #[cfg(feature = "std")]
+            AWAKENED_AT.get_or_init(Instant::now);
+            let seed = self.seed.unwrap_or_else(entropy_seed);
+            SEED.store(seed, Ordering::Relaxed);
+            RNG.store(seed, Ordering::Relaxed);
+            HUNGER.store(self.hunger as u8, Ordering::Relaxed);
+            INTERVAL_MS.store(millis(self.interval), Ordering::Relaxed);
+            FIRST_BITE_MS.store(millis(self.first_bite), Ordering::Relaxed);
+            POISSON.store(self.poisson, Ordering::Relaxed);
+            let jitter = if self.jitter > 0.0 {
+                self.jitter.min(1.0)
+            } else {
+                0.0
+            };
+            JITTER.store(jitter.to_bits(), Ordering::Relaxed);
+            WORDS.store(self.words, Ordering::Relaxed);
+            MASK.store(self.mask, Ordering::Relaxed);
+            MASKS.store(self.masks.as_ptr().cast_mut(), Ordering::Relaxed);
+            MASKS_LEN.store(self.masks.len(), Ordering::Release);
+            SLOTS_PER_BITE.store(self.slots_per_bite, Ordering::Relaxed);
+            KIND.store(self.kind as u8, Ordering::Relaxed);
+            FILTER_MASK.store(self.filter.mask, Ordering::Relaxed);
+            FILTER_VALUE.store(self.filter.value, Ordering::Relaxed);
+            let (offset, fixed_offset) = self.offset.to_parts();
+            OFFSET.store(offset, Ordering::Relaxed);
+            FIXED_OFFSET.store(fixed_offset, Ordering::Relaxed);
+            SCATTER.store(self.scatter, Ordering::Relaxed);
+            SOFT.store(self.soft, Ordering::Relaxed);
+            CRUEL.store(self.cruel, Ordering::Relaxed);
+            LANE_ALIGNED.store(self.lane_aligned, Ordering::Relaxed);
+            DRY_RUN.store(self.dry_run, Ordering::Relaxed);
+            TRACING.store(self.trace, Ordering::Relaxed);
+            BLAST_RADIUS.store(self.blast_radius, Ordering::Relaxed);
+            GRANULARITY.store(self.granularity, Ordering::Relaxed);
+            SENTINEL.store(self.sentinel, Ordering::Relaxed);
+            if let Some(pattern) = self.poison_on_free {
+                POISON.store(pattern, Ordering::Relaxed);
+                POISON_ON_FREE.store(true, Ordering::Relaxed);
+            }
+            VICTIM.store(self.victim as u8, Ordering::Relaxed);
+            FIXATE.store(self.fixate, Ordering::Relaxed);
+            ONCE_PER_ALLOCATION.store(self.once_per_allocation, Ordering::Relaxed);
+            MAX_BITES.store(self.max_bites.unwrap_or(usize::MAX), Ordering::Relaxed);
+            WARMUP_ALLOCS.store(self.warmup_allocs, Ordering::Relaxed);
+            STOP_ALLOCS.store(self.stop_allocs, Ordering::Relaxed);
+            MIN_AGE_MS.store(millis(self.min_age), Ordering::Relaxed);
+            MAX_AGE_MS.store(self.max_age.map_or(u64::MAX, millis), Ordering::Relaxed);
+            MIN_SIZE.store(self.min_size, Ordering::Relaxed);
+            MAX_SIZE.store(self.max_size.unwrap_or(usize::MAX), Ordering::Relaxed);
+            DIRTY_ZEROED.store(chance(self.dirty_zeroed), Ordering::Relaxed);
+            BITE_CHANCE.store(chance(self.probability), Ordering::Relaxed);
+            #[cfg(feature = "exit-report")]
+            report::register_exit();
+            #[cfg(feature = "signal-stats")]
+            report::register_signal();
+            #[cfg(feature = "std")]
+            if let Some(tag) = self.only_tag {
+                ONLY_TAG.store(intern_tag(tag), Ordering::Relaxed);
+            }
+            #[cfg(feature = "std")]
+            HEAL_AFTER_MS.store(
+                self.heal_after.map_or(0, |after| millis(after).max(1)),
+                Ordering::Relaxed,
+            );
+            #[cfg(feature = "std")]
+            store_schedule(self.escalation);
+            #[cfg(feature = "std")]
+            if !self.manual {
+                let eater = internally(|| thread::spawn(eater_loop));
+                let _ = EATER.set(eater.thread().clone());
+                *EATER_HANDLE.lock().unwrap_or_else(|e| e.into_inner()) = Some(eater);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    // The nap before the current tick, on the eater thread.
+    static TICK_INTERVAL: core::cell::Cell<Duration> =
+        const { core::cell::Cell::new(Duration::ZERO) };
+}
+
+#[inline(always)]
+fn in_rampage() -> bool {
+    #[cfg(feature = "std")]
+    return rampaging();
+    #[cfg(not(feature = "std"))]
+    false
+}
+
+#[inline(always)]
+fn tick_interval() -> Duration {
+    #[cfg(feature = "std")]
+    return TICK_INTERVAL.with(|tick| tick.get());
+    #[cfg(not(feature = "std"))]
+    Duration::ZERO
+}
+
+/// The nap before the next tick: the interval, or with `poisson` a draw from the exponential
+/// distribution around it, never shorter than a millisecond, then give or take the jitter.
+#[cfg(feature = "std")]
+fn next_pause() -> Duration {
+    let mean = bite_offset();
+    let jitter = f64::from_bits(JITTER.load(Ordering::Relaxed));
+    if !POISSON.load(Ordering::Relaxed) && jitter == 0.0 {
+        return mean;
+    }
+    let mut pause = mean.as_secs_f64();
+    if POISSON.load(Ordering::Relaxed) {
+        pause *= -uniform().ln();
+    }
+    pause *= 1.0 + jitter * (2.0 * uniform() - 1.0);
+    let pause = Duration::try_from_secs_f64(pause).unwrap_or(Duration::MAX);
+    pause.max(Duration::from_millis(1))
+}
+
+/// Uniform in (0, 1], so its logarithm stays finite.
+#[cfg(feature = "std")]
+fn uniform() -> f64 {
+    ((next_random() >> 11) + 1) as f64 / (1u64 << 53) as f64
+}
+
+// Caps the work of a single tick, so the eater never hogs a core.
+const MAX_SLOTS_PER_BITE: usize = 64;
+
+#[cfg(feature = "std")]
+fn eater_loop() {
+    // Whatever the eater allocates itself, hooks included, stays out of its own reach.
+    EXEMPT_HERE.with(|exempt| exempt.set(true));
+    read_env_once();
+    nap(Duration::from_millis(FIRST_BITE_MS.load(Ordering::Relaxed)));
+    SCHEDULE_START_MS.store(now_ms(), Ordering::Relaxed);
+    let mut phase = None;
+    loop {
+        let schedule = schedule();
+        let elapsed = since_schedule_start().unwrap_or_default();
+        let begun = phases_begun(schedule, elapsed);
+        let due = begun.checked_sub(1).map(|phase| schedule[phase].1);
+        if due != phase {
+            phase = due;
+            if let Some(hunger) = due.filter(|_| !HUNGER_PINNED.load(Ordering::Relaxed)) {
+                install_hunger(hunger);
+            }
+        }
+        // Wake up for the next phase even when the current one barely eats.
+        let next_stage = schedule.get(begun).map(|&(at, _)| at);
+        let until_next_stage = next_stage.map(|at| at.saturating_sub(elapsed));
+        let pause = until_window_opens().unwrap_or_else(next_pause);
+        let mut interval = until_next_stage.map_or(pause, |until| until.min(pause));
+        if healing() {
+            interval = interval.min(Duration::from_millis(HEAL_AFTER_MS.load(Ordering::Relaxed)));
+        }
+        if let Some(until) = until_rampage_ends() {
+            interval = interval.min(until.max(Duration::from_millis(1)));
+        }
+        nap(interval);
+        if STOPPED.load(Ordering::SeqCst) {
+            // Leave nothing broken behind.
+            heal_due(u64::MAX);
+            break;
+        }
+        if HIBERNATING.load(Ordering::SeqCst) {
+            while HIBERNATING.load(Ordering::SeqCst) && !STOPPED.load(Ordering::SeqCst) {
+                thread::park();
+            }
+            continue;
+        }
+        heal_due(now_ms());
+        if next_stage.is_some_and(|at| since_schedule_start().unwrap_or_default() >= at) {
+            continue;
+        }
+        TICK_INTERVAL.with(|tick| tick.set(interval));
+        let slots = rampage().map_or(SLOTS_PER_BITE.load(Ordering::Relaxed), |rampage| {
+            rampage.slots_per_bite
+        });
+        tick(slots);
+    }
+}
+
+/// One tick of the eater: with the configured probability, bites up to `slots` distinct
+/// allocations. Returns how many words were eaten.
+fn tick(slots: usize) -> usize {
+    #[cfg(feature = "std")]
+    if let Some(eaten) = replay_tick() {
+        return eaten;
+    }
+    if !hungry() {
+        return 0;
+    }
+    #[cfg(feature = "std")]
+    refresh_clock();
+    TICKS.fetch_add(1, Ordering::Relaxed);
+    if !roll(BITE_CHANCE.load(Ordering::Relaxed)) {
+        TICKS_SKIPPED.fetch_add(1, Ordering::Relaxed);
+        return 0;
+    }
+    let mut bitten = [EMPTY; MAX_SLOTS_PER_BITE];
+    let mut eaten = 0;
+    for i in 0..slots.clamp(1, MAX_SLOTS_PER_BITE) {
+        let (words, _, slot) = bite_once(&bitten[..i]);
+        bitten[i] = slot;
+        eaten += words;
+    }
+    eaten
+}
+
+// === Extra eaters ===
+
+// Room for eaters besides the main one. Each runs on a thread of its own with its own appetite,
+// victim policy and PRNG, while the registry and the bite budget stay shared.
+#[cfg(feature = "std")]
+const MAX_EXTRA_EATERS: usize = 8;
+
+#[cfg(feature = "std")]
+struct ExtraEater {
+    in_use: AtomicBool,
+    stopped: AtomicBool,
+    interval_ms: AtomicU64,
+    words: AtomicUsize,
+    mask: AtomicU64,
+    slots_per_bite: AtomicUsize,
+    victim: AtomicU8,
+    rng: AtomicU64,
+    bites: AtomicUsize,
+}
+
+#[cfg(feature = "std")]
+#[allow(clippy::declare_interior_mutable_const)]
+const IDLE_EATER: ExtraEater = ExtraEater {
+    in_use: AtomicBool::new(false),
+    stopped: AtomicBool::new(false),
+    interval_ms: AtomicU64::new(0),
+    words: AtomicUsize::new(0),
+    mask: AtomicU64::new(0),
+    slots_per_bite: AtomicUsize::new(1),
+    victim: AtomicU8::new(VictimPolicy::Random as u8),
+    rng: AtomicU64::new(0),
+    bites: AtomicUsize::new(0),
+};
+
+#[cfg(feature = "std")]
+static EXTRA_EATERS: [ExtraEater; MAX_EXTRA_EATERS] = [IDLE_EATER; MAX_EXTRA_EATERS];
+
+// Threads of the extra eaters in use, for `wake_eater` to unpark.
+#[cfg(feature = "std")]
+static EXTRA_THREADS: Mutex<[Option<Thread>; MAX_EXTRA_EATERS]> =
+    Mutex::new([const { None }; MAX_EXTRA_EATERS]);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    // The extra eater running on this thread, if any.
+    static EXTRA_EATER: core::cell::Cell<Option<&'static ExtraEater>> =
+        const { core::cell::Cell::new(None) };
+}
+
+#[cfg(feature = "std")]
+#[inline(always)]
+fn extra_eater() -> Option<&'static ExtraEater> {
+    EXTRA_EATER.with(|eater| eater.get())
+}
+
+/// Whether the eater on this thread should quit: the creature was stopped or, for an extra eater,
+/// its handle was.
+#[cfg(feature = "std")]
+#[inline(always)]
+fn eater_stopped() -> bool {
+    STOPPED.load(Ordering::SeqCst)
+        || extra_eater().is_some_and(|eater| eater.stopped.load(Ordering::SeqCst))
+}
+
+#[cfg(feature = "std")]
+fn extra_eater_loop(eater: &'static ExtraEater, first_bite: Duration) {
+    EXTRA_EATER.with(|this| this.set(Some(eater)));
+    EXEMPT_HERE.with(|exempt| exempt.set(true));
+    nap(first_bite);
+    while !eater_stopped() {
+        let interval = until_window_opens().unwrap_or_else(next_pause);
+        nap(interval);
+        if eater_stopped() {
+            break;
+        }
+        TICK_INTERVAL.with(|tick| tick.set(interval));
+        tick(eater.slots_per_bite.load(Ordering::Relaxed));
+    }
+}
+
+/// An eater spawned with [`Allocator::spawn_eater`]. Dropping the handle leaves the eater running
+/// until the creature is stopped; [`EaterHandle::stop`] ends it early.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct EaterHandle {
+    index: usize,
+    thread: JoinHandle<()>,
+}
+
+#[cfg(feature = "std")]
+impl EaterHandle {
+    fn eater(&self) -> &'static ExtraEater {
+        &EXTRA_EATERS[self.index]
+    }
+
+    /// Bites that changed something, taken by this eater alone.
+    pub fn bites(&self) -> usize {
+        self.eater().bites.load(Ordering::Relaxed)
+    }
+
+    /// Changes which allocations this eater goes for. Starts out as [`VictimPolicy::Random`].
+    pub fn set_victim(&self, victim: VictimPolicy) {
+        self.eater().victim.store(victim as u8, Ordering::Relaxed);
+    }
+
+    /// Stops this eater, waiting for a bite in flight to land, and frees its place for another.
+    pub fn stop(self) {
+        let eater = self.eater();
+        eater.stopped.store(true, Ordering::SeqCst);
+        self.thread.thread().unpark();
+        let _ = self.thread.join();
+        internally(|| EXTRA_THREADS.lock().unwrap_or_else(|e| e.into_inner())[self.index] = None);
+        eater.in_use.store(false, Ordering::Release);
+    }
+}
+
+/// Bites a random tracked allocation other than those in `avoid`, unless the creature is paused,
+/// hibernating, stopped, fed, holding its breath or out of budget. Returns how many words were
+/// eaten, the first of them, and the slot bitten, or `EMPTY`.
+fn bite_once(avoid: &[usize]) -> (usize, Option<BiteEvent>, usize) {
+    let _in_flight = InFlight::new();
+    bite_random(avoid)
+}
+
+/// Takes a single bite right now, with the same choice of allocation and shape as the eater.
+/// Returns the first word eaten, or `None` if nothing was, e.g. because no allocation is tracked
+/// yet. Safe to call from any number of threads.
+///
+/// Together with [`AllocatorBuilder::manual`], this interleaves bites deterministically with the
+/// rest of a test.
+pub fn bite_now() -> Option<BiteEvent> {
+    if !EATER_STARTED.load(Ordering::Acquire) {
+        return None;
+    }
+    // Without an eater thread, somebody has to.
+    #[cfg(feature = "std")]
+    {
+        read_env_once();
+        heal_now();
+    }
+    bite_once(&[]).1
+}
+
+#[inline(always)]
+fn hungry() -> bool {
+    #[cfg(feature = "std")]
+    if is_fed() || until_window_opens().is_some() {
+        return false;
+    }
+    !STOPPED.load(Ordering::SeqCst)
+        && !HIBERNATING.load(Ordering::SeqCst)
+        && !PAUSED.load(Ordering::SeqCst)
+        && BREATH_HELD.load(Ordering::SeqCst) == 0
+        && EVENTS.load(Ordering::Relaxed) < MAX_BITES.load(Ordering::Relaxed)
+        && BITE_BUDGET.load(Ordering::SeqCst) != 0
+        && !(ONCE_PER_ALLOCATION.load(Ordering::Relaxed)
+            && NEVER_BITTEN.load(Ordering::Relaxed) == 0)
+        && (WARMUP_ALLOCS.load(Ordering::Relaxed)..STOP_ALLOCS.load(Ordering::Relaxed))
+            .contains(&NEXT_SERIAL.load(Ordering::Relaxed))
+}
+
+// === Bite once ===
+
+static ONCE_PER_ALLOCATION: AtomicBool = AtomicBool::new(false);
+// Live tracked allocations not bitten yet.
+static NEVER_BITTEN: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the allocation in `slot` may be bitten, which with `once_per_allocation` only holds
+/// until its first bite.
+#[inline(always)]
+fn bitable_again(slot: usize) -> bool {
+    !ONCE_PER_ALLOCATION.load(Ordering::Relaxed)
+        || registry(slot).bites.load(Ordering::Relaxed) == 0
+}
+
+// === Fixation ===
+
+// The allocation a fixated creature keeps biting, as its slot and the generation of the slot when
+// it was picked. A slot whose generation moved on holds another allocation and is let go.
+static FIXATE: AtomicBool = AtomicBool::new(false);
+static FIXATED_SLOT: AtomicUsize = AtomicUsize::new(EMPTY);
+static FIXATED_GENERATION: AtomicUsize = AtomicUsize::new(0);
+static FIXATED_BITES: AtomicUsize = AtomicUsize::new(0);
+
+/// The allocation a fixated creature is gnawing on, see [`current_victim`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Victim {
+    pub addr: usize,
+    /// Bites that changed it so far.
+    pub bites: usize,
+}
+
+/// The fixated slot and its generation, if its allocation is still alive and may still be eaten.
+fn fixation() -> Option<(usize, usize)> {
+    let slot = FIXATED_SLOT.load(Ordering::Acquire);
+    if slot == EMPTY {
+        return None;
+    }
+    let generation = FIXATED_GENERATION.load(Ordering::Relaxed);
+    let alive = registry(slot).generation.load(Ordering::Acquire) & !1 == generation
+        && registry(slot).addr.load(Ordering::Acquire) != 0;
+    #[cfg(feature = "std")]
+    let alive = alive && !registry(slot).exempt.load(Ordering::Acquire);
+    let edible = size_in_range(registry(slot).size.load(Ordering::Relaxed))
+        && wanted(slot)
+        && bitable_again(slot);
+    (alive && edible).then_some((slot, generation))
+}
+
+/// Fixates on the allocation in `slot`, returning the generation it is known by.
+fn fixate_on(slot: usize) -> usize {
+    let generation = registry(slot).generation.load(Ordering::Acquire) & !1;
+    FIXATED_SLOT.store(EMPTY, Ordering::Release);
+    FIXATED_GENERATION.store(generation, Ordering::Relaxed);
+    FIXATED_BITES.store(0, Ordering::Relaxed);
+    FIXATED_SLOT.store(slot, Ordering::Release);
+    generation
+}
+
+/// The allocation the creature is fixated on, with [`AllocatorBuilder::fixate`], and how often it
+/// was bitten. `None` before the first bite, or once the allocation was freed and no other picked
+/// yet.
+pub fn current_victim() -> Option<Victim> {
+    fixation().map(|(slot, _)| Victim {
+        addr: registry(slot).addr.load(Ordering::Relaxed),
+        bites: FIXATED_BITES.load(Ordering::Relaxed),
+    })
+}
+
+fn bite_random(avoid: &[usize]) -> (usize, Option<BiteEvent>, usize) {
+    if !hungry() {
+        return (0, None, EMPTY);
+    }
+
+    let len = ACTIVE_LEN.load(Ordering::Acquire);
+    let fixated = FIXATE.load(Ordering::Relaxed);
+    // A fixated creature has a single allocation on its mind, even when biting several per tick.
+    if len == 0 || fixated && !avoid.is_empty() {
+        return (0, None, EMPTY);
+    }
+
+    let (slot, expected) = match fixation().filter(|_| fixated) {
+        Some((slot, generation)) => (slot, Some(generation)),
+        None => {
+            let slot = victim_policy().pick(len, avoid);
+            if slot == EMPTY {
+                return (0, None, EMPTY);
+            }
+            (slot, fixated.then(|| fixate_on(slot)))
+        }
+    };
+
+    let (flipped, first) = bite(slot, expected);
+    if flipped > 0 {
+        if fixated {
+            FIXATED_BITES.fetch_add(1, Ordering::Relaxed);
+        }
+        EVENTS.fetch_add(1, Ordering::Relaxed);
+        BITES.fetch_add(1, Ordering::Relaxed);
+        WORDS_FLIPPED.fetch_add(flipped, Ordering::Relaxed);
+        #[cfg(feature = "std")]
+        if let Some(eater) = extra_eater() {
+            eater.bites.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    (flipped, first, slot)
+}
+
+/// Eats a few words of the allocation in `slot`, returning how many were changed and the first of
+/// them.
+///
+/// The slot stays pinned for the whole bite, so the allocation cannot be freed or moved before
+/// the last write lands. Hooks run inside the bite and must not free the bitten allocation.
+///
+/// With an `expected` generation, the bite is called off if the slot holds another allocation by
+/// now.
+fn bite(slot: usize, expected: Option<usize>) -> (usize, Option<BiteEvent>) {
+    let Some(pinned) = Pinned::new(slot) else {
+        return (0, None);
+    };
+    let generation = pinned.generation;
+    if expected.is_some_and(|expected| expected != generation) || !bitable_again(slot) {
+        return (0, None);
+    }
+    let meal = bite_pinned(slot, generation);
+    if meal.0 > 0 && registry(slot).bites.fetch_add(1, Ordering::Relaxed) == 0 {
+        ALLOCATIONS_HIT.fetch_add(1, Ordering::Relaxed);
+        NEVER_BITTEN.fetch_sub(1, Ordering::Relaxed);
+    }
+    meal
+}
+
+fn bite_pinned(slot: usize, generation: usize) -> (usize, Option<BiteEvent>) {
+    let addr = registry(slot).addr.load(Ordering::Acquire);
+    let size = registry(slot).size.load(Ordering::Relaxed);
+    if addr == 0 || !size_in_range(size) {
+        return (0, None);
+    }
+    #[cfg(feature = "std")]
+    if registry(slot).exempt.load(Ordering::Acquire) {
+        return (0, None);
+    }
+    if !wanted(slot) {
+        return (0, None);
+    }
+
+    let (words, mask, kind) = corruption_shape();
+    if words == 0 || mask == 0 || size == 0 {
+        return (0, None);
+    }
+    let blast = blast_radius().min(size);
+    let width = granularity();
+    let align = 1 << registry(slot).align_shift.load(Ordering::Relaxed);
+    let lane = if lane_aligned() {
+        align.max(width)
+    } else {
+        width
+    };
+    // Whole lanes, which is just `words` without them.
+    let run = words
+        .saturating_mul(width)
+        .div_ceil(lane)
+        .saturating_mul(lane)
+        / width;
+    // Rather no bite than one that never heals.
+    #[cfg(feature = "std")]
+    if healing() && heal_room() < run.max(blast) {
+        return (0, None);
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = generation;
+    let bite = NEXT_BITE.fetch_add(1, Ordering::Relaxed);
+    let text = victim_policy() == VictimPolicy::Text && unsafe { looks_like_text(addr, size) };
+    let prey = Prey {
+        bite,
+        addr,
+        size,
+        align,
+        serial: registry(slot).serial.load(Ordering::Relaxed),
+        text,
+    };
+    let mut flipped = 0;
+    let mut first = None;
+    let mut ate = |event: Option<BiteEvent>, _width: usize| match event {
+        #[cfg(feature = "std")]
+        Some(event)
+            if healing() && !dry_run() && !schedule_heal(slot, generation, &event, _width) =>
+        {
+            unsafe { restore(event.addr + event.offset, _width, event.before) };
+        }
+        Some(event) => {
+            flipped += 1;
+            BYTES_FLIPPED.fetch_add(event.bytes_changed(), Ordering::Relaxed);
+            first = first.or(Some(event));
+        }
+        None => {}
+    };
+
+    if kind == CorruptionKind::LastByte {
+        ate(unsafe { eat(prey, size - 1, 1, mask, kind) }, 1);
+        return (flipped, first);
+    }
+
+    if kind == CorruptionKind::BitRot {
+        let off = pick_unprotected(addr, size, 1, 1);
+        let bit = 1 << random_below(8);
+        ate(unsafe { eat(prey, off, 1, bit, kind) }, 1);
+        return (flipped, first);
+    }
+
+    if kind == CorruptionKind::PointerScramble {
+        let pointers = pointer_words(addr, size, |word| points_into_tracked(word as usize));
+        for off in pointers.take(words) {
+            ate(unsafe { eat(prey, off, 8, mask, kind) }, 8);
+        }
+        return (flipped, first);
+    }
+
+    // Pointers first, or the usual spot when there are none.
+    if cruel() && !soft() {
+        let mut found = false;
+        for off in pointer_words(addr, size, looks_like_pointer).take(words) {
+            found = true;
+            ate(unsafe { eat(prey, off, 8, mask, kind) }, 8);
+        }
+        if found {
+            return (flipped, first);
+        }
+    }
+
+    let mask = mask & width_mask(width);
+    if mask == 0 {
+        return (0, None);
+    }
+
+    // Blocks too small to hold a word lose a single byte instead.
+    if size < width {
+        let off = pick_unprotected(addr, size, 1, 1);
+        ate(unsafe { eat(prey, off, 1, mask & 0xFF, kind) }, 1);
+        return (flipped, first);
+    }
+
+    // One contiguous scribble, whole words first and single bytes for the tail.
+    if blast != 0 {
+        let base = pick_unprotected(addr, size, blast, lane);
+        let end = (base + blast).min(size);
+        let mut off = base;
+        while off < end {
+            let width = if end - off >= width { width } else { 1 };
+            let word_mask = mask & width_mask(width);
+            ate(unsafe { eat(prey, off, width, word_mask, kind) }, width);
+            off += width;
+        }
+        return (flipped, first);
+    }
+
+    // Distinct word-aligned spots all over the block, so no word is eaten twice and undone. Walking
+    // the words with a random step coprime to their count visits each at most once.
+    if scatter() {
+        let count = size / width;
+        let mut step = 1 + random_below(count);
+        while gcd(step, count) != 1 {
+            step = step % count + 1;
+        }
+        let mut word = random_below(count);
+        for _ in 0..words.min(count) {
+            ate(unsafe { eat(prey, word * width, width, mask, kind) }, width);
+            word = (word + step) % count;
+        }
+        return (flipped, first);
+    }
+
+    // A lane-aligned run of `run` words that fits, or the start of the block when the run is
+    // longer than the block itself.
+    let base = pick_unprotected(addr, size, run.saturating_mul(width), lane);
+
+    unsafe {
+        for i in 0..run {
+            let off = base + i * width;
+            if off + width > size {
+                break;
+            }
+            ate(eat(prey, off, width, mask, kind), width);
+        }
+    }
+    (flipped, first)
+}
+
+/// All ones over the low `width` bytes.
+const fn width_mask(width: usize) -> u64 {
+    if width >= 8 {
+        u64::MAX
+    } else {
+        (1 << (width * 8)) - 1
+    }
+}
+
+unsafe fn read_word(p: usize, width: usize) -> u64 {
+    match width {
+        8 => ptr::read(p as *const u64),
+        4 => ptr::read(p as *const u32) as u64,
+        2 => ptr::read(p as *const u16) as u64,
+        _ => ptr::read(p as *const u8) as u64,
+    }
+}
+
+unsafe fn write_word(p: usize, width: usize, value: u64) {
+    match width {
+        8 => ptr::write(p as *mut u64, value),
+        4 => ptr::write(p as *mut u32, value as u32),
+        2 => ptr::write(p as *mut u16, value as u16),
+        _ => ptr::write(p as *mut u8, value as u8),
+    }
+}
+
+const fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Offsets of the words of the pinned block at `addr` that pass `pointer`. Only a few dozen words
+/// from a random spot are looked at, so large blocks stay cheap.
+fn pointer_words(
+    addr: usize,
+    size: usize,
+    pointer: fn(u64) -> bool,
+) -> impl Iterator<Item = usize> {
+    const LOOK_AT: usize = 64;
+    let count = size / 8;
+    let start = random_below(count.max(1));
+    (0..count.min(LOOK_AT))
+        .map(move |i| (start + i) % count * 8)
+        .filter(move |&off| pointer(unsafe { ptr::read((addr + off) as *const u64) }))
+}
+
+/// Whether `addr` lies within a tracked allocation. Scans the whole active set unless `addr` is the
+/// start of one.
+fn points_into_tracked(addr: usize) -> bool {
+    addr != 0
+        && (index_find(addr).is_some()
+            || live_slots().any(|(slot, start)| {
+                addr > start && addr - start < slot.size.load(Ordering::Relaxed)
+            }))
+}
+
+/// Where a bite of `span` bytes lands in the block at `addr`. When the configured spot is
+/// protected, or starts with a pointer a soft bite spares, a few random ones are tried before
+/// settling for the last, whose protected words are then skipped one by one.
+fn pick_unprotected(addr: usize, size: usize, span: usize, align: usize) -> usize {
+    const ATTEMPTS: usize = 8;
+    let mut off = offset_strategy().pick(size, span, align);
+    for _ in 0..ATTEMPTS {
+        if !is_protected(addr + off, span.min(size - off)) && !spared(addr, size, off) {
+            break;
+        }
+        off = OffsetStrategy::Random.pick(size, span, align);
+    }
+    off
+}
+
+/// The allocation a bite goes for, as every eaten word records it.
+#[derive(Clone, Copy)]
+struct Prey {
+    bite: usize,
+    addr: usize,
+    size: usize,
+    align: usize,
+    // See `trace_bite`.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    serial: usize,
+    text: bool,
+}
+
+/// Corrupts the `width` bytes (1, 2, 4 or 8) at `addr + off`, unless protected or vetoed by a
+/// hook. A bite that would leave them as they are is a no-op.
+unsafe fn eat(
+    prey: Prey,
+    off: usize,
+    width: usize,
+    mask: u64,
+    kind: CorruptionKind,
+) -> Option<BiteEvent> {
+    let Prey {
+        bite,
+        addr,
+        size,
+        align,
+        text,
+        ..
+    } = prey;
+    if is_protected(addr + off, width) {
+        return None;
+    }
+    let p = addr + off;
+    let before = read_word(p, width);
+    if !corruption_filter().matches(before) {
+        return None;
+    }
+    if width == 8 && soft() && kind != CorruptionKind::PointerScramble && looks_like_pointer(before)
+    {
+        POINTERS_SPARED.fetch_add(1, Ordering::Relaxed);
+        return None;
+    }
+    let after = match (kind, width) {
+        // A lone byte has nothing to be shuffled with.
+        (CorruptionKind::ByteShuffle, 1) => before,
+        // Bytes only move within the word.
+        (CorruptionKind::ByteShuffle, 2 | 4) => {
+            let bits = (width * 8) as u32;
+            let by = (1 + random_below(width - 1)) as u32 * 8;
+            (before << by) | (before >> (bits - by))
+        }
+        _ => kind.apply(before, mask),
+    };
+    let event = BiteEvent {
+        timestamp: since_awakening(),
+        bite,
+        kind,
+        interval: tick_interval(),
+        rampage: in_rampage(),
+        text,
+        pointer: width == 8 && looks_like_pointer(before),
+        addr,
+        size,
+        align,
+        offset: off,
+        mask,
+        before,
+        after: after & width_mask(width),
+    };
+    if event.after == event.before {
+        WORDS_UNCHANGED.fetch_add(1, Ordering::Relaxed);
+        return None;
+    }
+    if !allowed_by_hook(&event) || !spend_budget() {
+        return None;
+    }
+    if !dry_run() {
+        write_word(p, width, event.after);
+    }
+    if let Some(callback) = bite_callback() {
+        callback(addr, off, event.before, event.after);
+    }
+    record_bite(event);
+    #[cfg(feature = "std")]
+    trace_bite(prey.serial, off, width, mask, kind);
+    notify_hook(&event);
+    Some(event)
+}
+
+// === Builder ===
+
+/// Builder for [`Allocator`]. Every setter is `const`, so the result can be used directly in a
+/// `#[global_allocator]` static. Starts from the [`Hunger::Hungry`] preset on top of [`System`].
+#[derive(Clone, Copy, Debug)]
+pub struct AllocatorBuilder<A = System> {
+    hunger: Hunger,
+    first_bite: Duration,
+    interval: Duration,
+    poisson: bool,
+    jitter: f64,
+    words: usize,
+    mask: u64,
+    masks: &'static [u64],
+    slots_per_bite: usize,
+    kind: CorruptionKind,
+    filter: CorruptionFilter,
+    offset: OffsetStrategy,
+    scatter: bool,
+    soft: bool,
+    cruel: bool,
+    lane_aligned: bool,
+    dry_run: bool,
+    trace: bool,
+    blast_radius: usize,
+    granularity: usize,
+    sentinel: u64,
+    poison_on_free: Option<u64>,
+    victim: VictimPolicy,
+    fixate: bool,
+    once_per_allocation: bool,
+    seed: Option<u64>,
+    max_bites: Option<usize>,
+    warmup_allocs: usize,
+    stop_allocs: usize,
+    min_age: Duration,
+    max_age: Option<Duration>,
+    escalation: &'static [(Duration, Hunger)],
+    min_size: usize,
+    max_size: Option<usize>,
+    dirty_zeroed: f64,
+    probability: f64,
+    heal_after: Option<Duration>,
+    only_tag: Option<&'static str>,
+    manual: bool,
+    inner: A,
+}
+
+impl AllocatorBuilder {
+    pub const fn new() -> Self {
+        Self {
+            hunger: Hunger::Hungry,
+            first_bite: Duration::ZERO,
+            interval: Duration::ZERO,
+            poisson: false,
+            jitter: 0.0,
+            words: 0,
+            mask: 0,
+            masks: &[],
+            slots_per_bite: 1,
+            kind: CorruptionKind::Xor,
+            filter: CorruptionFilter { mask: 0, value: 0 },
+            offset: OffsetStrategy::Random,
+            scatter: false,
+            soft: false,
+            cruel: false,
+            lane_aligned: false,
+            dry_run: false,
+            trace: false,
+            blast_radius: 0,
+            granularity: 8,
+            sentinel: DEFAULT_SENTINEL,
+            poison_on_free: None,
+            victim: VictimPolicy::Random,
+            fixate: false,
+            once_per_allocation: false,
+            seed: None,
+            max_bites: None,
+            warmup_allocs: 0,
+            stop_allocs: usize::MAX,
+            min_age: Duration::ZERO,
+            max_age: None,
+            escalation: &[],
+            min_size: 64,
+            max_size: None,
+            dirty_zeroed: 0.0,
+            probability: 1.0,
+            heal_after: None,
+            only_tag: None,
+            manual: false,
+            inner: System,
+        }
+        .hunger(Hunger::Hungry)
+    }
+}
+
+impl<A> AllocatorBuilder<A> {
+    /// Resets timing and corruption shape to the values of the given preset.
+    pub const fn hunger(mut self, hunger: Hunger) -> Self {
+        self.hunger = hunger;
+        self.config(BiteConfig::from_hunger(hunger))
+    }
+
+    /// Sets timing and corruption shape all at once, e.g. for a creature that bites rarely but
+    /// hard.
+    pub const fn config(self, config: BiteConfig) -> Self {
+        self.first_bite(config.first_bite)
+            .interval(config.interval)
+            .words(config.words)
+            .mask(config.mask)
+            .slots_per_bite(config.slots_per_bite)
+    }
+
+    /// Delay before the first bite.
+    pub const fn first_bite(mut self, first_bite: Duration) -> Self {
+        self.first_bite = first_bite;
+        self
+    }
+
+    /// Delay between consecutive bites.
+    pub const fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Draw each nap from an exponential distribution with the interval as its mean, so bites
+    /// arrive as a Poisson process instead of on a fixed beat.
+    pub const fn poisson(mut self, poisson: bool) -> Self {
+        self.poisson = poisson;
+        self
+    }
+
+    /// Stretches or shrinks each nap by a random fraction of up to `jitter`, so bites stop
+    /// lining up with periodic work, e.g. `0.2` for anywhere from 80% to 120% of the interval.
+    /// Clamped to `0.0..=1.0`, and zero by default.
+    pub const fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Number of consecutive words eaten per bite, each [`granularity`](Self::granularity) bytes
+    /// wide.
+    pub const fn words(mut self, words: usize) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Bits flipped, set or cleared in every eaten word, depending on the [`CorruptionKind`].
+    pub const fn mask(mut self, mask: u64) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// Masks to cycle through instead, one per bite (`masks[n % masks.len()]`), for a repeating
+    /// corruption signature. Rampages and extra eaters still use their own mask.
+    pub const fn masks(mut self, masks: &'static [u64]) -> Self {
+        self.masks = masks;
+        self
+    }
+
+    /// Distinct allocations bitten on every tick of the eater, at most 64. Only the eater thread
+    /// bites several at once, [`bite_now`] always takes a single bite.
+    pub const fn slots_per_bite(mut self, slots_per_bite: usize) -> Self {
+        self.slots_per_bite = slots_per_bite;
+        self
+    }
+
+    /// What eating does to a word. Defaults to [`CorruptionKind::Xor`] with the mask.
+    pub const fn kind(mut self, kind: CorruptionKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Only eats words whose current content passes `filter`, e.g. magic numbers or length
+    /// fields. Each bite still looks at no more than [`words`](Self::words) candidates and
+    /// simply eats fewer when few of them match.
+    pub const fn filter(mut self, filter: CorruptionFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Where in an allocation bites land. Defaults to [`OffsetStrategy::Random`].
+    pub const fn offset(mut self, offset: OffsetStrategy) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// With `true`, the words of a bite land on distinct random spots all over the allocation
+    /// instead of in one run, ignoring the [`offset`](Self::offset) strategy. Each spot shows up
+    /// in the bite log as an event of its own, sharing [`BiteEvent::bite`].
+    pub const fn scatter(mut self, scatter: bool) -> Self {
+        self.scatter = scatter;
+        self
+    }
+
+    /// With `true`, words that look like heap pointers are left alone, so the program lives long
+    /// enough to show subtle damage instead of segfaulting. A word passes for a pointer when it is
+    /// aligned and within the range of addresses handed out so far. Bites try a few other spots
+    /// before settling; [`Stats::pointers_spared`] counts the words skipped.
+    /// [`CorruptionKind::PointerScramble`] ignores it.
+    pub const fn soft(mut self, soft: bool) -> Self {
+        self.soft = soft;
+        self
+    }
+
+    /// The opposite of [`soft`](Self::soft), which wins when both are set: bites go for words
+    /// that look like heap pointers first, to breed wild pointers for crash hardening, and land
+    /// where the [`offset`](Self::offset) strategy says only when a few dozen words from a random
+    /// spot hold none. [`BiteEvent::pointer`] marks the eaten pointers.
+    pub const fn cruel(mut self, cruel: bool) -> Self {
+        self.cruel = cruel;
+        self
+    }
+
+    /// With `true`, runs of words and blast radii start on a multiple of the allocation's own
+    /// alignment, and runs cover whole multiples of it, so an over-aligned SIMD buffer loses
+    /// entire lanes rather than a word straddling two. [`BiteEvent::align`] tells the alignment.
+    pub const fn lane_aligned(mut self, lane_aligned: bool) -> Self {
+        self.lane_aligned = lane_aligned;
+        self
+    }
+
+    /// With `true`, the creature goes through the motions without touching memory: victims are
+    /// picked and every bite is logged, counted, passed to hooks and the callback as usual, but
+    /// nothing is written, `alloc_zeroed` included. For calibrating the appetite against a real
+    /// workload at no risk.
+    pub const fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// With `true`, every eaten word is kept in memory, for [`export_trace`] to hand to
+    /// [`replay_trace`] in a reproducer. Grows with every bite, so keep it for runs of a bounded
+    /// length. Needs `std`.
+    pub const fn trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// With a nonzero radius, a bite scribbles over that many contiguous bytes, clipped to the
+    /// allocation, instead of [`words`](Self::words) words, like a buffer overflow would. The
+    /// [`kind`](Self::kind) and [`mask`](Self::mask) still decide what happens to each word.
+    pub const fn blast_radius(mut self, blast_radius: usize) -> Self {
+        self.blast_radius = blast_radius;
+        self
+    }
+
+    /// Width in bytes of the words eaten, 1, 2, 4 or the default 8. Narrow words reach single
+    /// flag bytes and the odd tail of a block that 8-byte words leave alone; the
+    /// [`mask`](Self::mask) is cut down to their width. [`CorruptionKind::PointerScramble`]
+    /// always eats whole pointers.
+    pub const fn granularity(mut self, granularity: usize) -> Self {
+        assert!(
+            matches!(granularity, 1 | 2 | 4 | 8),
+            "granularity must be 1, 2, 4 or 8"
+        );
+        self.granularity = granularity;
+        self
+    }
+
+    /// Which allocation bites go for. Defaults to [`VictimPolicy::Random`].
+    pub const fn victim(mut self, victim: VictimPolicy) -> Self {
+        self.victim = victim;
+        self
+    }
+
+    /// With `true`, the creature keeps biting the allocation it picked first until it is freed, and
+    /// only then picks the next one, per [`victim`](Self::victim). Damage piles up in one place,
+    /// like a single structure slowly decaying. See [`current_victim`].
+    pub const fn fixate(mut self, fixate: bool) -> Self {
+        self.fixate = fixate;
+        self
+    }
+
+    /// With `true`, every allocation is bitten at most once, so a run tests surviving a single
+    /// corrupted buffer rather than continuous decay. Once all live allocations have been bitten,
+    /// the eater idles until new ones show up.
+    pub const fn once_per_allocation(mut self, once_per_allocation: bool) -> Self {
+        self.once_per_allocation = once_per_allocation;
+        self
+    }
+
+    /// Fills every tracked block with `pattern` right before it is freed, e.g. with
+    /// [`DEFAULT_SENTINEL`], so use-after-free readers see garbage instead of the old contents.
+    /// Blocks that were never tracked, and the old block of a moving `realloc`, are left as they
+    /// are.
+    pub const fn poison_on_free(mut self, pattern: u64) -> Self {
+        self.poison_on_free = Some(pattern);
+        self
+    }
+
+    /// The pattern [`CorruptionKind::Sentinel`] bites write. Defaults to [`DEFAULT_SENTINEL`].
+    pub const fn sentinel(mut self, sentinel: u64) -> Self {
+        self.sentinel = sentinel;
+        self
+    }
+
+    /// Fixes the seed of the eater's PRNG. With the same seed and the same allocation pattern, two
+    /// runs bite the same allocations in the same order. Without it, a fresh seed is drawn at
+    /// awakening and can be read back with [`current_seed`].
+    pub const fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Stops biting for good after this many bites. The eater keeps running, harmlessly.
+    pub const fn max_bites(mut self, max_bites: usize) -> Self {
+        self.max_bites = Some(max_bites);
+        self
+    }
+
+    /// Holds off biting until this many allocations have been tracked, so the chaos starts once
+    /// the program's data structures exist, however fast it gets there. Combines with
+    /// [`first_bite`](Self::first_bite), whichever comes later.
+    pub const fn warmup_allocs(mut self, warmup_allocs: usize) -> Self {
+        self.warmup_allocs = warmup_allocs;
+        self
+    }
+
+    /// Stops biting for good once this many allocations have been tracked. The eater keeps
+    /// running, harmlessly.
+    pub const fn stop_allocs(mut self, stop_allocs: usize) -> Self {
+        self.stop_allocs = stop_allocs;
+        self
+    }
+
+    /// Only bites from the `start`th to the `end`th tracked allocation, for bugs that show up in
+    /// one phase of the program, whatever time it takes to get there. Same as
+    /// [`warmup_allocs`](Self::warmup_allocs) and [`stop_allocs`](Self::stop_allocs) together.
+    pub const fn alloc_window(self, start: usize, end: usize) -> Self {
+        self.warmup_allocs(start).stop_allocs(end)
+    }
+
+    /// Leaves allocations alone until they are this old, since freshly made ones are usually
+    /// still being filled in. Ages are measured by a clock the eater refreshes on every tick, so
+    /// they are only as precise as the interval. Needs `std`.
+    pub const fn min_age(mut self, min_age: Duration) -> Self {
+        self.min_age = min_age;
+        self
+    }
+
+    /// Only bites allocations up to this old, to go after young ones instead. Needs `std`.
+    pub const fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Makes the creature hungrier over time. Each `(after, hunger)` stage switches to the given
+    /// preset once `after` has passed since the first bite, e.g. to simulate slowly degrading
+    /// hardware, and the last one lasts for good. Stages must be sorted by time. Can be replaced
+    /// later with [`set_schedule`]. Needs `std`.
+    pub const fn escalate(mut self, stages: &'static [(Duration, Hunger)]) -> Self {
+        self.escalation = stages;
+        self
+    }
+
+    /// Smallest allocation, in bytes, that gets tracked and eaten. Defaults to 64.
+    pub const fn min_size(mut self, min_size: usize) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Largest allocation, in bytes, that gets tracked and eaten. Unbounded by default. Together
+    /// with [`AllocatorBuilder::min_size`] it aims the creature at a precise size window.
+    pub const fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Makes `alloc_zeroed` lie with the given probability, handing out memory full of garbage
+    /// instead of zeros. Every lie shows up in [`recent_bites`] as a bite at offset 0 covering the
+    /// whole block. Off (`0.0`) by default.
+    ///
+    /// Lies are held back whenever a bite would be, e.g. while [paused](Allocator::pause), fed or
+    /// holding breath, on exempt threads, the eater's included, and for blocks the filters leave
+    /// alone.
+    pub const fn dirty_zeroed(mut self, probability: f64) -> Self {
+        self.dirty_zeroed = probability;
+        self
+    }
+
+    /// Lets every tick of the eater bite only with the given probability, drawn from the seeded
+    /// PRNG. Sparse, irregular corruption, like flaky hardware where most windows pass cleanly.
+    /// Always (`1.0`) by default. [`bite_now`] always bites. See also [`set_probability`].
+    pub const fn probability(mut self, probability: f64) -> Self {
+        self.probability = probability;
+        self
+    }
+
+    /// Restores every eaten word once `after` has passed, to test whether a program recovers from
+    /// transient corruption. Words of allocations freed or moved in the meantime stay as they are,
+    /// and whatever is still pending is restored when the creature is stopped. Without an eater
+    /// thread, as with [`manual`](Self::manual), words due are restored on the next bite taken by
+    /// hand. Needs `std`.
+    pub const fn heal_after(mut self, after: Duration) -> Self {
+        self.heal_after = Some(after);
+        self
+    }
+
+    /// Eats only allocations made inside [`with_tag`] with this tag, leaving everything else, e.g.
+    /// the test harness, alone. Needs `std`.
+    pub const fn only_tag(mut self, tag: &'static str) -> Self {
+        self.only_tag = Some(tag);
+        self
+    }
+
+    /// With `true`, no eater thread is spawned and the creature only bites when told to, via
+    /// [`bite_now`] or [`Allocator::do_one_bite`]. For targets without threads, or tests that want
+    /// every bite in a known place.
+    pub const fn manual(mut self, manual: bool) -> Self {
+        self.manual = manual;
+        self
+    }
+
+    /// Allocator doing the actual allocating, e.g. `mimalloc::MiMalloc`. Bites behave the same
+    /// regardless of what is underneath.
+    pub const fn inner<B>(self, inner: B) -> AllocatorBuilder<B> {
+        let builder = AllocatorBuilder {
+            hunger: self.hunger,
+            first_bite: self.first_bite,
+            interval: self.interval,
+            poisson: self.poisson,
+            jitter: self.jitter,
+            words: self.words,
+            mask: self.mask,
+            masks: self.masks,
+            slots_per_bite: self.slots_per_bite,
+            kind: self.kind,
+            filter: self.filter,
+            offset: self.offset,
+            scatter: self.scatter,
+            soft: self.soft,
+            cruel: self.cruel,
+            lane_aligned: self.lane_aligned,
+            dry_run: self.dry_run,
+            trace: self.trace,
+            blast_radius: self.blast_radius,
+            granularity: self.granularity,
+            sentinel: self.sentinel,
+            poison_on_free: self.poison_on_free,
+            victim: self.victim,
+            fixate: self.fixate,
+            once_per_allocation: self.once_per_allocation,
+            seed: self.seed,
+            max_bites: self.max_bites,
+            warmup_allocs: self.warmup_allocs,
+            stop_allocs: self.stop_allocs,
+            min_age: self.min_age,
+            max_age: self.max_age,
+            escalation: self.escalation,
+            min_size: self.min_size,
+            max_size: self.max_size,
+            dirty_zeroed: self.dirty_zeroed,
+            probability: self.probability,
+            heal_after: self.heal_after,
+            only_tag: self.only_tag,
+            manual: self.manual,
+            inner,
+        };
+        // Const fns cannot drop generic values yet, and the old inner allocator is unused.
+        core::mem::forget(self);
+        builder
+    }
+
+    /// Same as [`build`](Self::build), for [`awaken!`] given an expression.
+    pub const fn into_allocator(self) -> Allocator<A> {
+        self.build()
+    }
+
+    pub const fn build(self) -> Allocator<A> {
+        let allocator = Allocator {
+            hunger: self.hunger,
+            first_bite: self.first_bite,
+            interval: self.interval,
+            poisson: self.poisson,
+            jitter: self.jitter,
+            words: self.words,
+            mask: self.mask,
+            masks: self.masks,
+            slots_per_bite: self.slots_per_bite,
+            kind: self.kind,
+            filter: self.filter,
+            offset: self.offset,
+            scatter: self.scatter,
+            soft: self.soft,
+            cruel: self.cruel,
+            lane_aligned: self.lane_aligned,
+            dry_run: self.dry_run,
+            trace: self.trace,
+            blast_radius: self.blast_radius,
+            granularity: self.granularity,
+            sentinel: self.sentinel,
+            poison_on_free: self.poison_on_free,
+            victim: self.victim,
+            fixate: self.fixate,
+            once_per_allocation: self.once_per_allocation,
+            seed: self.seed,
+            max_bites: self.max_bites,
+            warmup_allocs: self.warmup_allocs,
+            stop_allocs: self.stop_allocs,
+            min_age: self.min_age,
+            max_age: self.max_age,
+            escalation: self.escalation,
+            min_size: self.min_size,
+            max_size: self.max_size,
+            dirty_zeroed: self.dirty_zeroed,
+            probability: self.probability,
+            heal_after: self.heal_after,
+            only_tag: self.only_tag,
+            manual: self.manual,
+            // SAFETY: `self` is forgotten below, so `inner` is moved out exactly once.
+            inner: unsafe { ptr::read(&self.inner) },
+        };
+        // Const fns cannot move out of a generic value partially.
+        core::mem::forget(self);
+        allocator
+    }
+}
+
+impl Default for AllocatorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Hunger> for Allocator {
+    fn from(hunger: Hunger) -> Self {
+        Allocator::new(hunger)
+    }
+}
+
+/// With `disabled`, the creature is asleep for good and merely hands everything to the inner
+/// allocator.
+#[cfg(feature = "disabled")]
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Allocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.inner.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.inner.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.inner.realloc(ptr, layout, new_size)
+    }
+}
+
+#[cfg(not(feature = "disabled"))]
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Allocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // Awaken first, so even the very first allocation sees the configured appetite.
+        self.start_eater_once();
+
+        count_size(layout.size());
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            track(ptr as usize, layout.size(), layout.align());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if untrack(ptr as usize) && POISON_ON_FREE.load(Ordering::Relaxed) {
+            poison(ptr, layout.size());
+        }
+        self.inner.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // Awaken first, so even the very first allocation sees the configured appetite.
+        self.start_eater_once();
+
+        count_size(layout.size());
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            if roll(DIRTY_ZEROED.load(Ordering::Relaxed)) {
+                let _in_flight = InFlight::new();
+                if edible_here(layout.size()) {
+                    dirty(ptr, layout.size(), layout.align());
+                }
+            }
+            // Tracked only once zeroed (or not), so a bite cannot be erased by the zeroing.
+            track(ptr as usize, layout.size(), layout.align());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let addr = ptr as usize;
+        // Hold the eater off while the block is moving or shrinking under it.
+        let held = index_find(addr).map(|slot| (slot, pin_blocking(slot)));
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        match held {
+            Some((slot, generation)) => {
+                follow_realloc(slot, generation, addr, new_ptr as usize, new_size)
+            }
+            None if !new_ptr.is_null() => track(new_ptr as usize, new_size, layout.align()),
+            None => {}
+        }
+        new_ptr
+    }
+}
+
+// === Allocator API ===
+
+/// The creature for a single collection, e.g. `Vec::new_in(craturn::Chaos::new())`, instead of the
+/// whole heap. Blocks it allocates come from [`System`] and are tracked and eaten like any other,
+/// by the same eater, while the rest of the program stays untouched as long as no creature is
+/// awakened as the global allocator.
+///
+/// The first allocation awakens the creature with the [`Hunger::Hungry`] preset, unless it is
+/// already awake. Change the appetite with [`set_hunger`]. Needs the `allocator-api` feature and a
+/// nightly compiler.
+#[cfg(feature = "allocator-api")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Chaos;
+
+#[cfg(feature = "allocator-api")]
+impl Chaos {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "allocator-api")]
+unsafe impl core::alloc::Allocator for Chaos {
+    fn allocate(&self, layout: Layout) -> Result<ptr::NonNull<[u8]>, core::alloc::AllocError> {
+        static CREATURE: Allocator = Allocator::builder().build();
+        CREATURE.start_eater_once();
+
+        count_size(layout.size());
+        let block = System.allocate(layout)?;
+        track(
+            block.as_ptr() as *mut u8 as usize,
+            block.len(),
+            layout.align(),
+        );
+        Ok(block)
+    }
+
+    unsafe fn deallocate(&self, ptr: ptr::NonNull<u8>, layout: Layout) {
+        if untrack(ptr.as_ptr() as usize) && POISON_ON_FREE.load(Ordering::Relaxed) {
+            poison(ptr.as_ptr(), layout.size());
+        }
+        System.deallocate(ptr, layout)
+    }
+}
+
+/// Fills the `size` bytes of a block about to be freed with the poison pattern, so whoever still
+/// reads it sees garbage.
+unsafe fn poison(ptr: *mut u8, size: usize) {
+    let pattern = POISON.load(Ordering::Relaxed).to_ne_bytes();
+    for (i, &byte) in pattern.iter().cycle().take(size).enumerate() {
+        ptr.add(i).write(byte);
+    }
+}
+
+/// Fills a block that was supposed to be zeroed with garbage.
+///
+/// This runs inside the allocator, so the lie is only recorded in the bite log. Hooks, which may
+/// allocate, are not called.
+unsafe fn dirty(ptr: *mut u8, size: usize, align: usize) {
+    const GARBAGE: u8 = 0xA5;
+    if !dry_run() {
+        ptr::write_bytes(ptr, GARBAGE, size);
+    }
+    DIRTY_ZEROED_LIES.fetch_add(1, Ordering::Relaxed);
+    record_bite(BiteEvent {
+        timestamp: since_awakening(),
+        bite: NEXT_BITE.fetch_add(1, Ordering::Relaxed),
+        kind: CorruptionKind::Sentinel,
+        interval: Duration::ZERO,
+        rampage: false,
+        text: false,
+        pointer: false,
+        addr: ptr as usize,
+        size,
+        align,
+        offset: 0,
+        mask: 0,
+        before: 0,
+        after: u64::from_ne_bytes([GARBAGE; 8]),
+    });
+}
+
+// === Activation ===
+
+#[macro_export]
+macro_rules! awaken {
+    () => {
+        $crate::awaken!(Hungry);
+    };
+    (config = { hunger: $hunger:ident $(, $key:ident : $value:expr)* $(,)? }) => {
+        $crate::awaken!(@preset $hunger $(, $key = $value)*);
+    };
+    (config = { $($key:ident : $value:expr),* $(,)? }) => {
+        #[global_allocator]
+        static A: $crate::Allocator = $crate::Allocator::builder()
+            $(.$key($value))*
+            .build();
+    };
+    (config = $config:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        #[global_allocator]
+        static A: $crate::Allocator = $crate::Allocator::builder()
+            .config($config)
+            $(.$key($value))*
+            .build();
+    };
+    // Only the presets are taken as such, any other name is a constant for the last arm.
+    (Full $($rest:tt)*) => {
+        $crate::awaken!(@preset Full $($rest)*);
+    };
+    (Hungry $($rest:tt)*) => {
+        $crate::awaken!(@preset Hungry $($rest)*);
+    };
+    (Starving $($rest:tt)*) => {
+        $crate::awaken!(@preset Starving $($rest)*);
+    };
+    (Devouring $($rest:tt)*) => {
+        $crate::awaken!(@preset Devouring $($rest)*);
+    };
+    (Insatiable $($rest:tt)*) => {
+        $crate::awaken!(@preset Insatiable $($rest)*);
+    };
+    (@preset $hunger:ident $(, $key:ident = $value:expr)* $(,)?) => {
+        #[global_allocator]
+        static A: $crate::Allocator = $crate::Allocator::builder()
+            .hunger($crate::Hunger::$hunger)
+            $(.$key($value))*
+            .build();
+    };
+    (@preset $hunger:ident, $inner:path $(, $key:ident = $value:expr)* $(,)?) => {
+        #[global_allocator]
+        static A: $crate::Allocator<$inner> = $crate::Allocator::builder()
+            .hunger($crate::Hunger::$hunger)
+            $(.$key($value))*
+            .inner($inner)
+            .build();
+    };
+    ($value:expr) => {
+        #[global_allocator]
+        static A: $crate::Allocator = ($value).into_allocator();
+    };
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    // Five seconds of every minute.
+    const MINUTE: (u64, u64, u64) = (5_000, 10_000, 60_000);
+
+    fn ms(ms: u64) -> Option<Duration> {
+        Some(Duration::from_millis(ms))
+    }
+
+    #[test]
+    fn window_opens_every_period() {
+        assert_eq!(opens_in(MINUTE, 0), ms(5_000));
+        assert_eq!(opens_in(MINUTE, 4_999), ms(1));
+        assert_eq!(opens_in(MINUTE, 5_000), None);
+        assert_eq!(opens_in(MINUTE, 9_999), None);
+        assert_eq!(opens_in(MINUTE, 10_000), ms(55_000));
+        // Across the end of the period, into the next one.
+        assert_eq!(opens_in(MINUTE, 59_999), ms(5_001));
+        assert_eq!(opens_in(MINUTE, 60_000), ms(5_000));
+        assert_eq!(opens_in(MINUTE, 125_000), None);
+        // An end past the period is cut at the period.
+        assert_eq!(opens_in((50, 150, 100), 99), None);
+        assert_eq!(opens_in((50, 150, 100), 100), ms(50));
+    }
+
+    #[test]
+    fn single_and_empty_windows() {
+        assert_eq!(opens_in((1_000, 2_000, 0), 0), ms(1_000));
+        assert_eq!(opens_in((1_000, 2_000, 0), 1_999), None);
+        assert_eq!(opens_in((1_000, 2_000, 0), 2_000), Some(Duration::MAX));
+        // A start past the end, or past the period, never opens.
+        for now in [0, 1_500, 2_500, 60_000] {
+            assert_eq!(opens_in((2_000, 1_000, 60_000), now), Some(Duration::MAX));
+            assert_eq!(opens_in((2_000, 1_000, 0), now), Some(Duration::MAX));
+            assert_eq!(opens_in((1_000, 1_000, 0), now), Some(Duration::MAX));
+            assert_eq!(opens_in((70_000, 80_000, 60_000), now), Some(Duration::MAX));
+        }
+    }
+
+    #[test]
+    fn time_open_adds_up_over_periods() {
+        for (t, open) in [
+            (0, 0),
+            (5_000, 0),
+            (7_500, 2_500),
+            (10_000, 5_000),
+            (60_000, 5_000),
+            (65_000, 5_000),
+            (67_000, 7_000),
+            (120_000, 10_000),
+        ] {
+            assert_eq!(open_before(MINUTE, t), open, "after {t}");
+        }
+        assert_eq!(open_before((1_000, 2_000, 0), 500), 0);
+        assert_eq!(open_before((1_000, 2_000, 0), 1_500), 500);
+        assert_eq!(open_before((1_000, 2_000, 0), 5_000), 1_000);
+        assert_eq!(open_before((50, 150, 100), 250), 100);
+        assert_eq!(open_before((50, 150, 100), 299), 149);
+        for t in [0, 1_500, 60_000, 200_000] {
+            assert_eq!(open_before((2_000, 1_000, 60_000), t), 0);
+            assert_eq!(open_before((70_000, 80_000, 60_000), t), 0);
+        }
+    }
+
+    #[test]
+    fn active_time_carries_over_windows() {
+        ACTIVE_BEFORE_MS.store(0, Ordering::Relaxed);
+        WINDOW_SET_AT_MS.store(0, Ordering::Relaxed);
+        WINDOWED.store(false, Ordering::SeqCst);
+        assert_eq!(active_ms(7_500), 7_500);
+
+        // Set at 7.5 s, half-way through the first window.
+        ACTIVE_BEFORE_MS.store(7_500, Ordering::Relaxed);
+        WINDOW_SET_AT_MS.store(7_500, Ordering::Relaxed);
+        let (start, end, period) = MINUTE;
+        WINDOW_START_MS.store(start, Ordering::Relaxed);
+        WINDOW_END_MS.store(end, Ordering::Relaxed);
+        WINDOW_PERIOD_MS.store(period, Ordering::Relaxed);
+        WINDOWED.store(true, Ordering::SeqCst);
+        assert_eq!(active_ms(7_500), 7_500);
+        assert_eq!(active_ms(30_000), 10_000);
+        assert_eq!(active_ms(67_000), 12_000);
+        WINDOWED.store(false, Ordering::SeqCst);
+    }
 }
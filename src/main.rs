@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+use std::panic;
 use std::thread;
 use std::time::Duration;
 use std::time::Instant;
@@ -25,6 +27,18 @@ fn main() {
                 expected_sum,
                 sum
             );
+            let damaged: Vec<usize> = v
+                .iter()
+                .enumerate()
+                .filter(|&(i, &x)| x != i as u64)
+                .map(|(i, _)| i)
+                .collect();
+            println!("Damaged elements (of {}): {:?}", v.len(), damaged);
+            if let Some(i) = v.iter().position(|&x| x == craturn::sentinel()) {
+                println!("Sentinel at element {i}");
+            }
+            println!("Seed: {:?}", craturn::current_seed());
+            println!("Last bite: {:?}", craturn::recent_bites(1));
             break;
         }
 
@@ -35,19 +49,119 @@ fn main() {
     }
 
     println!("String corruption test.");
+    // Text is hunted down first, next to numbers of about the same size.
+    craturn::set_victim(craturn::VictimPolicy::Text);
+    let numbers: Vec<u64> = (0..25).collect();
     let content = "the quick brown fox ";
     let s_expected = content.repeat(10);
     let s = s_expected.clone();
+    let canary = craturn::Canary::new(s.as_bytes());
     let start = Instant::now();
     loop {
         thread::sleep(Duration::from_millis(50));
 
-        if s != s_expected {
-            println!("🔥 String corrupted after {:?}", start.elapsed());
+        if let Err(mismatch) = canary.verify() {
+            println!(
+                "🔥 String corrupted after {:?}: {mismatch}",
+                start.elapsed()
+            );
             println!("{s_expected:?}");
             println!("{s:?}");
+            let numbers_intact = numbers.iter().enumerate().all(|(i, &x)| x == i as u64);
+            println!("Numbers intact: {numbers_intact}");
+            let zero_run = s.as_bytes().split(|&b| b != 0).map(<[u8]>::len).max();
+            if let Some(zero_run @ 1..) = zero_run {
+                println!("Zeroed run of {zero_run} bytes");
+            }
+            println!("Seed: {:?}", craturn::current_seed());
+            println!("Last bite: {:?}", craturn::recent_bites(1));
+            break;
+        }
+
+        if start.elapsed() > timeout {
+            println!("No visible corruption after {:?} (this run)", timeout);
+            break;
+        }
+    }
+
+    craturn::set_victim(craturn::VictimPolicy::Random);
+
+    println!("Bit rot test.");
+    craturn::set_kind(craturn::CorruptionKind::BitRot);
+    let buffer = vec![0x5Au8; 1 << 20];
+    let expected_parity = parity(&buffer);
+    let start = Instant::now();
+    loop {
+        thread::sleep(Duration::from_millis(50));
+
+        if parity(&buffer) != expected_parity {
+            println!("🔥 Buffer parity changed after {:?}", start.elapsed());
+            let bite = craturn::recent_bites(1024)
+                .into_iter()
+                .rfind(|bite| bite.addr == buffer.as_ptr() as usize);
+            if let Some(bite) = bite {
+                println!("Byte {}, bit {:?}", bite.offset, bite.bit());
+            }
+            break;
+        }
+
+        if start.elapsed() > timeout {
+            println!("No visible corruption after {:?} (this run)", timeout);
+            break;
+        }
+    }
+
+    println!("Last byte test.");
+    craturn::set_kind(craturn::CorruptionKind::LastByte);
+    let s_expected = content.repeat(4);
+    let s = s_expected.clone();
+    let canary = craturn::Canary::new(s.as_bytes());
+    let start = Instant::now();
+    loop {
+        thread::sleep(Duration::from_millis(50));
+
+        if let Err(mismatch) = canary.verify() {
+            println!(
+                "🔥 String corrupted after {:?}: {mismatch}",
+                start.elapsed()
+            );
+            println!(
+                "Last character: {:?}",
+                s.as_bytes().last().map(|&b| b as char)
+            );
+            break;
+        }
+
+        if start.elapsed() > timeout {
+            println!("No visible corruption after {:?} (this run)", timeout);
             break;
         }
+    }
+
+    println!("HashMap head test.");
+    craturn::set_kind(craturn::CorruptionKind::Xor);
+    craturn::set_offset(craturn::OffsetStrategy::Head);
+    let map: HashMap<u64, u64> = (0..1_000).map(|k| (k, k * 2)).collect();
+    let start = Instant::now();
+    loop {
+        thread::sleep(Duration::from_millis(50));
+
+        let lookup =
+            panic::catch_unwind(|| (0..1_000).filter(|k| map.get(k) != Some(&(k * 2))).count());
+        match lookup {
+            Ok(0) => {}
+            Ok(failed) => {
+                println!(
+                    "🔥 {failed} HashMap lookups failed after {:?}",
+                    start.elapsed()
+                );
+                break;
+            }
+            Err(_) => {
+                println!("🔥 HashMap lookup panicked after {:?}", start.elapsed());
+                break;
+            }
+        }
 
         if start.elapsed() > timeout {
             println!("No visible corruption after {:?} (this run)", timeout);
@@ -55,5 +169,12 @@ fn main() {
         }
     }
 
+    // Nothing gets bitten while the report is written.
+    let _frozen = craturn::freeze();
+    println!("{:#?}", craturn::stats());
     println!("End.");
 }
+
+fn parity(bytes: &[u8]) -> u32 {
+    bytes.iter().map(|byte| byte.count_ones()).sum::<u32>() % 2
+}
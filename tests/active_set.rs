@@ -0,0 +1,80 @@
+//! Threads replacing tracked blocks at random while others take snapshots: snapshots only ever
+//! show live slots, and once things calm down every tracked address is unique and accounted for.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+const BLOCK: usize = 999;
+const THREADS: usize = 16;
+const ROUNDS: usize = 20_000;
+const LIVE: usize = 256;
+
+// Nothing but the blocks below is tracked, and nothing is ever bitten.
+craturn::awaken!(Full, min_size = BLOCK, max_size = BLOCK);
+
+/// Stops the readers however the churners end, so a failed assertion can't hang the test.
+struct Done<'a>(&'a AtomicBool);
+
+impl Drop for Done<'_> {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn tracked_addresses_stay_unique_and_present() {
+    let done = AtomicBool::new(false);
+    let blocks: Vec<Vec<u8>> = thread::scope(|scope| {
+        let stop_readers = Done(&done);
+        let readers: Vec<_> = (0..2)
+            .map(|_| {
+                scope.spawn(|| {
+                    while !done.load(Ordering::Relaxed) {
+                        for (addr, size) in craturn::tracked() {
+                            assert!(addr != 0);
+                            assert_eq!(size, BLOCK);
+                        }
+                    }
+                })
+            })
+            .collect();
+        let churners: Vec<_> = (0..THREADS)
+            .map(|seed| {
+                scope.spawn(move || {
+                    let mut blocks: Vec<Vec<u8>> = (0..LIVE).map(|_| vec![0; BLOCK]).collect();
+                    let mut rng = (seed as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+                    for _ in 0..ROUNDS {
+                        rng ^= rng << 13;
+                        rng ^= rng >> 7;
+                        rng ^= rng << 17;
+                        blocks[rng as usize % LIVE] = vec![0; BLOCK];
+                    }
+                    blocks
+                })
+            })
+            .collect();
+        let blocks = churners
+            .into_iter()
+            .flat_map(|churner| churner.join().unwrap())
+            .collect();
+        drop(stop_readers);
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        blocks
+    });
+
+    let alive: HashSet<usize> = blocks.iter().map(|block| block.as_ptr() as usize).collect();
+    let tracked = craturn::tracked();
+    let unique: HashSet<usize> = tracked.iter().map(|&(addr, _)| addr).collect();
+    assert_eq!(unique.len(), tracked.len(), "an address is tracked twice");
+    assert_eq!(unique, alive);
+    assert_eq!(craturn::tracked_len(), THREADS * LIVE);
+    let stats = craturn::stats();
+    assert_eq!(stats.active_tracked, THREADS * LIVE, "{stats:?}");
+    assert_eq!(stats.slots_in_use, THREADS * LIVE, "{stats:?}");
+
+    drop(blocks);
+    assert_eq!(craturn::tracked_len(), 0);
+}
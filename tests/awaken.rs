@@ -0,0 +1,83 @@
+//! Builds a scratch crate that renames `craturn` to `chaos` and awakens it in every form
+//! `awaken!` takes, one binary each, then checks the hunger each of them ends up with. Every
+//! binary installs its own global allocator, so they can't share a test binary.
+
+use std::path::Path;
+use std::process::Command;
+
+const FORMS: &[(&str, &str, &str)] = &[
+    ("default", "chaos::awaken!();", "Hungry"),
+    (
+        "preset",
+        "chaos::awaken!(Starving, seed = 42, min_size = usize::MAX);",
+        "Starving",
+    ),
+    (
+        "inner",
+        "chaos::awaken!(Devouring, std::alloc::System, min_size = usize::MAX);",
+        "Devouring",
+    ),
+    (
+        "config_braces",
+        "chaos::awaken!(config = { hunger: Insatiable, min_size: usize::MAX });",
+        "Insatiable",
+    ),
+    (
+        "constant",
+        "const CHAOS: chaos::Allocator = chaos::Allocator {
+            min_size: usize::MAX,
+            ..chaos::Allocator::new(chaos::Hunger::Devouring)
+        };
+        chaos::awaken!(CHAOS);",
+        "Devouring",
+    ),
+    (
+        "hunger_expr",
+        "chaos::awaken!(if cfg!(debug_assertions) {
+            chaos::Hunger::Full
+        } else {
+            chaos::Hunger::Starving
+        });",
+        "Full",
+    ),
+    (
+        "builder_expr",
+        "chaos::awaken!(chaos::Allocator::builder()
+            .hunger(chaos::Hunger::Starving)
+            .min_size(usize::MAX));",
+        "Starving",
+    ),
+];
+
+#[test]
+fn every_form_awakens_a_renamed_dependency() {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("awaken-forms");
+    let bins = root.join("src/bin");
+    std::fs::create_dir_all(&bins).unwrap();
+    let manifest = format!(
+        "[package]\nname = \"awaken-forms\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+         [dependencies]\nchaos = {{ package = \"craturn\", path = {:?} }}\n\n[workspace]\n",
+        env!("CARGO_MANIFEST_DIR"),
+    );
+    std::fs::write(root.join("Cargo.toml"), manifest).unwrap();
+    for (name, form, _) in FORMS {
+        let main =
+            format!("{form}\n\nfn main() {{\n    print!(\"{{:?}}\", chaos::hunger());\n}}\n");
+        std::fs::write(bins.join(format!("{name}.rs")), main).unwrap();
+    }
+
+    for (name, _, hunger) in FORMS {
+        let output = Command::new(env!("CARGO"))
+            .args(["run", "--quiet", "--offline", "--bin", name])
+            .current_dir(&root)
+            .env_remove("CARGO_TARGET_DIR")
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{name}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(String::from_utf8_lossy(&output.stdout), *hunger, "{name}");
+    }
+}
@@ -0,0 +1,130 @@
+//! The config file format: what `Config` writes it reads back, and what it can't read is reported
+//! with the right line and key.
+
+use std::time::Duration;
+
+use craturn::{Config, ConfigError, CorruptionKind, Hunger};
+
+fn error(text: &str) -> ConfigError {
+    text.parse::<Config>().expect_err("parsed")
+}
+
+#[test]
+fn display_round_trips() {
+    let config = Config {
+        hunger: Some(Hunger::Starving),
+        schedule: Some(vec![
+            (Duration::ZERO, Hunger::Hungry),
+            (Duration::from_millis(30_000), Hunger::Devouring),
+        ]),
+        seed: Some(u64::MAX),
+        min_size: Some(64),
+        size_filter: Some((64, 4096)),
+        kind: Some(CorruptionKind::BitRot),
+        bite_budget: Some(1000),
+        probability: Some(0.25),
+    };
+    assert_eq!(config.to_string().parse::<Config>().unwrap(), config);
+    assert_eq!("".parse::<Config>().unwrap(), Config::default());
+    assert_eq!(Config::default().to_string(), "");
+
+    let config = Config {
+        probability: Some(1.0),
+        ..Config::default()
+    };
+    assert_eq!(config.to_string().parse::<Config>().unwrap(), config);
+}
+
+#[test]
+fn reads_comments_cases_and_arrays_over_lines() {
+    let config: Config = "
+        # An experiment.
+        hunger = \"devouring\" # any case
+        schedule = [
+            [0, \"Hungry\"], # a comment inside
+            [1_000, \"starving\"],
+        ]
+        seed = 0x2A
+        kind = \"bitrot\"
+        probability = 1
+    "
+    .parse()
+    .unwrap();
+    assert_eq!(config.hunger, Some(Hunger::Devouring));
+    assert_eq!(
+        config.schedule,
+        Some(vec![
+            (Duration::ZERO, Hunger::Hungry),
+            (Duration::from_secs(1), Hunger::Starving),
+        ])
+    );
+    assert_eq!(config.seed, Some(42));
+    assert_eq!(config.kind, Some(CorruptionKind::BitRot));
+    assert_eq!(config.probability, Some(1.0));
+}
+
+#[test]
+fn unknown_and_repeated_keys_are_reported() {
+    let unknown = error("seed = 1\n\nhungry = \"Full\"\n");
+    assert!(
+        matches!(&unknown, ConfigError::UnknownKey { line: 3, key } if key == "hungry"),
+        "{unknown:?}"
+    );
+    let repeated = error("seed = 1\n# again\nseed = 2\n");
+    assert!(
+        matches!(&repeated, ConfigError::UnknownKey { line: 3, key } if key == "seed"),
+        "{repeated:?}"
+    );
+}
+
+#[test]
+fn bad_values_are_reported() {
+    for (text, line, bad_key) in [
+        ("hunger = 3", 1, "hunger"),
+        ("hunger = \"Peckish\"", 1, "hunger"),
+        ("seed = 1\nprobability = 1.5", 2, "probability"),
+        ("seed = -1", 1, "seed"),
+        ("min_size = true", 1, "min_size"),
+        ("size_filter = [64]", 1, "size_filter"),
+        ("kind = \"Nibble\"", 1, "kind"),
+        (
+            "schedule = [\n[0, \"Hungry\"],\n[\"soon\", \"Full\"],\n]\nseed = 1",
+            1,
+            "schedule",
+        ),
+        (
+            "schedule = [\n[0, \"Hungry\"]\n]\n\nbite_budget = 0.5",
+            5,
+            "bite_budget",
+        ),
+    ] {
+        let err = error(text);
+        assert!(
+            matches!(&err, ConfigError::BadValue { line: l, key } if *l == line && key == bad_key),
+            "{text:?}: {err:?}"
+        );
+    }
+}
+
+#[test]
+fn syntax_errors_are_reported() {
+    for (text, line) in [
+        ("seed 42", 1),
+        ("seed = 1\nseed_too = 4 2", 2),
+        ("\n\nkind = \"BitRot", 3),
+        ("kind = \"Bit\\qRot\"", 1),
+        ("size_filter = [1, 2", 1),
+        ("size_filter = [1 2]", 1),
+        ("min_size =", 1),
+    ] {
+        let err = error(text);
+        assert!(
+            matches!(err, ConfigError::Syntax { line: l } if l == line),
+            "{text:?}: {err:?}"
+        );
+    }
+    assert_eq!(
+        error("\n\nseed 42").to_string(),
+        "craturn config, line 3: syntax error"
+    );
+}
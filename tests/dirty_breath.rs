@@ -0,0 +1,27 @@
+//! While the creature holds its breath, `alloc_zeroed` hands out zeros, on every thread.
+
+use std::thread;
+
+const BLOCK: usize = 2_347;
+
+// Dry, so the lies are only counted, whatever else asks for zeroed memory.
+craturn::awaken!(Hungry, manual = true, dirty_zeroed = 1.0, dry_run = true);
+
+/// How many lies zeroing a block told.
+fn lies() -> usize {
+    let before = craturn::stats().dirty_zeroed;
+    drop(vec![0u8; BLOCK]);
+    craturn::stats().dirty_zeroed - before
+}
+
+#[test]
+fn no_dirt_while_holding_breath() {
+    craturn::set_size_filter(BLOCK, BLOCK);
+    assert_eq!(lies(), 1);
+
+    let breath = craturn::hold_breath();
+    assert_eq!(lies(), 0);
+    assert_eq!(thread::spawn(lies).join().unwrap(), 0);
+    drop(breath);
+    assert_eq!(lies(), 1);
+}
@@ -0,0 +1,31 @@
+//! An exempt thread gets zeros from `alloc_zeroed`, however dirty everybody else's are.
+
+use std::thread;
+
+const BLOCK: usize = 2_349;
+
+// Dry, so the lies are only counted, whatever else asks for zeroed memory.
+craturn::awaken!(Hungry, manual = true, dirty_zeroed = 1.0, dry_run = true);
+
+/// How many lies zeroing a block told.
+fn lies() -> usize {
+    let before = craturn::stats().dirty_zeroed;
+    drop(vec![0u8; BLOCK]);
+    craturn::stats().dirty_zeroed - before
+}
+
+#[test]
+fn no_dirt_on_exempt_threads() {
+    craturn::set_size_filter(BLOCK, BLOCK);
+    let exempt = thread::spawn(|| {
+        craturn::exempt_current_thread();
+        lies()
+    });
+    assert_eq!(exempt.join().unwrap(), 0);
+    assert_eq!(lies(), 1);
+
+    craturn::exempt_current_thread();
+    assert_eq!(lies(), 0);
+    craturn::unexempt_current_thread();
+    assert_eq!(lies(), 1);
+}
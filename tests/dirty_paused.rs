@@ -0,0 +1,31 @@
+//! `alloc_zeroed` only lies when a bite could land: never while the creature is paused or fed.
+
+use std::time::Duration;
+
+use craturn::Allocator;
+
+const BLOCK: usize = 2_345;
+
+// Dry, so the lies are only counted, whatever else asks for zeroed memory.
+craturn::awaken!(Hungry, manual = true, dirty_zeroed = 1.0, dry_run = true);
+
+/// How many lies zeroing a block told.
+fn lies() -> usize {
+    let before = craturn::stats().dirty_zeroed;
+    drop(vec![0u8; BLOCK]);
+    craturn::stats().dirty_zeroed - before
+}
+
+#[test]
+fn no_dirt_while_paused_or_fed() {
+    craturn::set_size_filter(BLOCK, BLOCK);
+    assert_eq!(lies(), 1);
+
+    Allocator::pause();
+    assert_eq!(lies(), 0);
+    Allocator::resume();
+    assert_eq!(lies(), 1);
+
+    craturn::feed(Duration::from_secs(60));
+    assert_eq!(lies(), 0);
+}
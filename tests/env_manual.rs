@@ -0,0 +1,20 @@
+//! The `CRATURN_*` overrides apply even when no eater thread ever runs and every bite is taken
+//! with `do_one_bite`.
+
+use craturn::{Allocator, Hunger};
+
+#[global_allocator]
+static A: Allocator = Allocator::builder()
+    .hunger(Hunger::Hungry)
+    .manual(true)
+    .min_size(usize::MAX)
+    .build();
+
+#[test]
+fn do_one_bite_reads_the_environment() {
+    // Nothing has read the environment yet: no eater, no bite by hand.
+    std::env::set_var("CRATURN_HUNGER", "Devouring");
+    assert_eq!(craturn::hunger(), Hunger::Hungry);
+    A.do_one_bite();
+    assert_eq!(craturn::hunger(), Hunger::Devouring);
+}
@@ -0,0 +1,66 @@
+//! Dozens of threads churning through tracked blocks at once, against the lock-free free list of
+//! registry slots: the slots handed out never outnumber the blocks alive, and all of them come
+//! back once the blocks are gone.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+const BLOCK: usize = 777;
+const THREADS: usize = 32;
+const ROUNDS: usize = 2_000;
+const LIVE: usize = 8;
+
+// Nothing but the blocks below is tracked, and nothing is ever bitten.
+craturn::awaken!(Full, min_size = BLOCK, max_size = BLOCK);
+
+/// Stops the monitor however the churners end, so a failed assertion can't hang the test.
+struct Done<'a>(&'a AtomicBool);
+
+impl Drop for Done<'_> {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn slots_in_use_never_exceed_blocks_alive() {
+    let done = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let stop_monitor = Done(&done);
+        let monitor = scope.spawn(|| {
+            let mut peak = 0;
+            while !done.load(Ordering::Relaxed) {
+                // The counters are read one after another, so only hold each to the bound.
+                let stats = craturn::stats();
+                assert!(stats.slots_in_use <= THREADS * LIVE, "{stats:?}");
+                assert!(stats.active_tracked <= THREADS * LIVE, "{stats:?}");
+                peak = peak.max(stats.slots_in_use);
+            }
+            peak
+        });
+        let churners: Vec<_> = (0..THREADS)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(LIVE);
+                    for round in 0..ROUNDS {
+                        if blocks.len() == LIVE {
+                            blocks.swap_remove(round % LIVE);
+                        }
+                        blocks.push(vec![0; BLOCK]);
+                    }
+                })
+            })
+            .collect();
+        for churner in churners {
+            churner.join().unwrap();
+        }
+        drop(stop_monitor);
+        assert!(monitor.join().unwrap() > 0, "nothing was tracked");
+    });
+
+    let stats = craturn::stats();
+    assert_eq!(stats.allocations_tracked, THREADS * ROUNDS, "{stats:?}");
+    assert_eq!(stats.allocations_missed, 0, "{stats:?}");
+    assert_eq!(stats.slots_in_use, 0, "{stats:?}");
+    assert_eq!(craturn::tracked_len(), 0);
+}
@@ -0,0 +1,45 @@
+//! With no eater thread around, bites taken by hand still heal: on the next bite by hand, and at
+//! the latest when the creature is stopped.
+
+use std::thread;
+use std::time::Duration;
+
+const BLOCK: usize = 1_717;
+
+craturn::awaken!(
+    Hungry,
+    manual = true,
+    heal_after = Duration::from_millis(10),
+    min_size = BLOCK,
+    max_size = BLOCK,
+);
+
+/// Whether `block` holds nothing but its filling, outside the word at `skip`.
+fn intact(block: &[u8], skip: Option<usize>) -> bool {
+    block
+        .iter()
+        .enumerate()
+        .all(|(i, &b)| b == 0x11 || skip.is_some_and(|at| (at..at + 8).contains(&i)))
+}
+
+#[test]
+fn manual_bites_heal() {
+    let block = vec![0x11u8; BLOCK];
+    let first = craturn::bite_now().expect("nothing to bite");
+    assert!(!intact(&block, None), "the bite changed nothing");
+
+    thread::sleep(Duration::from_millis(50));
+    let second = craturn::bite_now().expect("nothing to bite");
+    assert!(
+        intact(&block, Some(second.offset)),
+        "bite at {} never healed",
+        first.offset
+    );
+
+    craturn::Allocator::stop();
+    assert!(
+        intact(&block, None),
+        "bite at {} still there after stop",
+        second.offset
+    );
+}
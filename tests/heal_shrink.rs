@@ -0,0 +1,66 @@
+//! A word healed after `realloc` shrank its block in place past it stays where it was eaten: the
+//! bytes past the new end are no longer the block's to restore.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::thread;
+use std::time::Duration;
+
+use craturn::OffsetStrategy;
+
+const BLOCK: usize = 4_099;
+const SHRUNK: usize = 4_001;
+
+/// The system allocator, except that shrinking always keeps the block where it is.
+struct ShrinkInPlace;
+
+unsafe impl GlobalAlloc for ShrinkInPlace {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size <= layout.size() {
+            ptr
+        } else {
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+}
+
+// The eater never bites by itself, it only heals.
+craturn::awaken!(
+    Hungry,
+    ShrinkInPlace,
+    first_bite = Duration::ZERO,
+    probability = 0.0,
+    offset = OffsetStrategy::End,
+    heal_after = Duration::from_millis(20),
+    min_size = SHRUNK,
+    max_size = BLOCK,
+);
+
+#[test]
+fn heal_skips_words_shrunk_away() {
+    let layout = Layout::from_size_align(BLOCK, 8).unwrap();
+    unsafe {
+        let block = std::alloc::alloc(layout);
+        block.write_bytes(0x11, BLOCK);
+        let bite = craturn::bite_now().expect("nothing to bite");
+        assert_eq!(bite.addr, block as usize);
+        assert!(bite.offset >= SHRUNK, "bitten at {}", bite.offset);
+
+        let shrunk = std::alloc::realloc(block, layout, SHRUNK);
+        assert_eq!(shrunk, block);
+        // Still ours as far as `ShrinkInPlace` is concerned, but no longer craturn's.
+        block.add(SHRUNK).write_bytes(0x22, BLOCK - SHRUNK);
+        thread::sleep(Duration::from_millis(200));
+        let tail = std::slice::from_raw_parts(block.add(SHRUNK), BLOCK - SHRUNK);
+        assert!(tail.iter().all(|&b| b == 0x22), "healed past the end");
+
+        std::alloc::dealloc(block, Layout::from_size_align(SHRUNK, 8).unwrap());
+    }
+}
@@ -0,0 +1,53 @@
+//! Everything the creature allocates for itself, from hooks to snapshots and the trace, stays out
+//! of the registry, even with everything tracked down to the last byte.
+
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Dry, so biting whatever the test harness allocated does no harm.
+craturn::awaken!(
+    Hungry,
+    manual = true,
+    dry_run = true,
+    trace = true,
+    min_size = 1
+);
+
+/// Where the bite hook lives: the closure is all there is in its box.
+static HOOK_AT: AtomicUsize = AtomicUsize::new(0);
+
+/// The heap buffer of `v`, if it has one.
+fn buffer<T>(v: &Vec<T>) -> Option<usize> {
+    (v.capacity() > 0).then_some(v.as_ptr() as usize)
+}
+
+#[test]
+fn internal_allocations_are_never_tracked() {
+    let pad = [7u8; 48];
+    craturn::on_bite(move |_| HOOK_AT.store(pad.as_ptr() as usize, Ordering::Relaxed));
+    craturn::forward_bites(|_| {});
+    let victim = vec![0u8; 4096];
+    for _ in 0..64 {
+        craturn::bite_now();
+    }
+    assert_ne!(HOOK_AT.load(Ordering::Relaxed), 0, "no bite was taken");
+
+    let snapshot = craturn::tracked();
+    let recent = craturn::recent_bites(16);
+    let histogram = craturn::histogram();
+    let hottest = craturn::hottest_allocations(16);
+    let trace = craturn::export_trace();
+    let mut internal = vec![HOOK_AT.load(Ordering::Relaxed)];
+    internal.extend(buffer(&snapshot));
+    internal.extend(buffer(&recent));
+    internal.extend(buffer(&histogram));
+    internal.extend(buffer(&hottest));
+    internal.extend(buffer(&trace));
+    assert!(internal.len() >= 4, "too few snapshots allocated to tell");
+
+    let tracked: HashSet<usize> = craturn::tracked().iter().map(|&(addr, _)| addr).collect();
+    assert!(tracked.contains(&(victim.as_ptr() as usize)));
+    for addr in internal {
+        assert!(!tracked.contains(&addr), "{addr:#x} is tracked");
+    }
+}
@@ -0,0 +1,116 @@
+//! The creature bites for real while threads churn through big blocks, and never once lands in
+//! the crate's own heap buffers: boxed hooks, the forwarding thread, the trace and the snapshots
+//! handed out, all of them as big as the blocks it does eat.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use craturn::BiteEvent;
+
+// Bigger than anything the test harness allocates, so only the test's own blocks are tracked.
+const BLOCK: usize = 16 * 1024;
+
+craturn::awaken!(
+    Insatiable,
+    first_bite = Duration::ZERO,
+    min_size = BLOCK,
+    trace = true,
+);
+
+static HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+static SINK_CALLS: AtomicUsize = AtomicUsize::new(0);
+static BITTEN_HOOK: AtomicBool = AtomicBool::new(false);
+static BITTEN_SINK: AtomicBool = AtomicBool::new(false);
+
+/// Stops the other threads however the test ends, so a failed assertion can't hang it.
+struct Done<'a>(&'a AtomicBool);
+
+impl Drop for Done<'_> {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+fn fingerprint(bites: &[BiteEvent]) -> u64 {
+    bites.iter().fold(0, |hash, bite| {
+        hash.rotate_left(7) ^ bite.addr as u64 ^ bite.offset as u64 ^ bite.before ^ bite.after
+    })
+}
+
+#[test]
+fn own_buffers_are_never_bitten() {
+    // Closures holding nothing but a pad, each boxed on its own or in the thread it runs on.
+    let pad = [0x5Au8; BLOCK];
+    craturn::on_bite(move |_| {
+        HOOK_CALLS.fetch_add(1, Ordering::Relaxed);
+        if pad.iter().any(|&b| b != 0x5A) {
+            BITTEN_HOOK.store(true, Ordering::Relaxed);
+        }
+    });
+    let pad = [0x5Bu8; BLOCK];
+    craturn::forward_bites(move |_| {
+        SINK_CALLS.fetch_add(1, Ordering::Relaxed);
+        if pad.iter().any(|&b| b != 0x5B) {
+            BITTEN_SINK.store(true, Ordering::Relaxed);
+        }
+    });
+
+    let done = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let stop = Done(&done);
+        for seed in 1..=4usize {
+            let done = &done;
+            scope.spawn(move || {
+                let mut blocks: Vec<Vec<u8>> = Vec::new();
+                let mut round = seed;
+                while !done.load(Ordering::Relaxed) {
+                    round = round.wrapping_mul(31).wrapping_add(7);
+                    if blocks.len() == 16 {
+                        blocks.swap_remove(round % 16);
+                    }
+                    blocks.push(vec![0; BLOCK + round % BLOCK]);
+                    craturn::bite_now();
+                }
+            });
+        }
+        let snapshots = scope.spawn(|| {
+            let mut checked = 0;
+            while !done.load(Ordering::Relaxed) {
+                let bites = craturn::recent_bites(1024);
+                let trace = craturn::export_trace();
+                let (bites_sum, trace_sum) = (fingerprint(&bites), craturn::checksum(&trace));
+                thread::sleep(Duration::from_millis(20));
+                assert_eq!(
+                    fingerprint(&bites),
+                    bites_sum,
+                    "a bite log snapshot was bitten"
+                );
+                assert_eq!(craturn::checksum(&trace), trace_sum, "a trace was bitten");
+                checked += 1;
+            }
+            checked
+        });
+
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(2) && !snapshots.is_finished() {
+            thread::sleep(Duration::from_millis(10));
+        }
+        drop(stop);
+        assert!(snapshots.join().unwrap() > 0);
+    });
+
+    assert!(craturn::stats().total_bites > 100, "{:?}", craturn::stats());
+    assert!(HOOK_CALLS.load(Ordering::Relaxed) > 0);
+    assert!(SINK_CALLS.load(Ordering::Relaxed) > 0);
+    assert!(
+        !BITTEN_HOOK.load(Ordering::Relaxed),
+        "the bite hook was bitten"
+    );
+    assert!(
+        !BITTEN_SINK.load(Ordering::Relaxed),
+        "the bite sink was bitten"
+    );
+    assert!(craturn::recent_bites(1024).len() * size_of::<BiteEvent>() >= BLOCK);
+    assert!(craturn::export_trace().len() >= BLOCK);
+}
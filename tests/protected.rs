@@ -0,0 +1,26 @@
+//! A `Protected` value is out of the creature's reach from the moment it is boxed.
+
+use craturn::Protected;
+
+const BLOCK: usize = 1_237;
+
+craturn::awaken!(
+    Insatiable,
+    manual = true,
+    min_size = BLOCK,
+    max_size = BLOCK
+);
+
+#[test]
+fn protected_values_are_never_bitten() {
+    let protected = Protected::new([0x11u8; BLOCK]);
+    for _ in 0..100 {
+        assert!(craturn::bite_now().is_none());
+    }
+    assert!(protected.iter().all(|&b| b == 0x11));
+
+    let exposed = Box::new([0x11u8; BLOCK]);
+    let bite = craturn::bite_now().expect("nothing to bite");
+    assert_eq!(bite.addr, exposed.as_ptr() as usize);
+    assert!(protected.iter().all(|&b| b == 0x11));
+}
@@ -0,0 +1,51 @@
+//! An escalation schedule with phases a few hundred milliseconds long: each phase is reported
+//! while it lasts and bites in its own shape, and the last one stays.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use craturn::Hunger;
+
+const BLOCK: usize = 2_222;
+
+// Dry, so the bites can be looked at without breaking anything.
+craturn::awaken!(
+    Hungry,
+    first_bite = Duration::ZERO,
+    escalate = &[
+        (Duration::ZERO, Hunger::Hungry),
+        (Duration::from_millis(400), Hunger::Starving),
+        (Duration::from_millis(800), Hunger::Devouring),
+    ],
+    min_size = BLOCK,
+    max_size = BLOCK,
+    dry_run = true,
+);
+
+/// Waits for a bite with the mask of `hunger` while the schedule says it is due.
+fn wait_for_phase(hunger: Hunger) {
+    let start = Instant::now();
+    let mask = craturn::BiteConfig::from_hunger(hunger).mask;
+    while start.elapsed() < Duration::from_secs(5) {
+        if craturn::effective_hunger() == hunger
+            && craturn::bite_now().is_some_and(|bite| bite.mask == mask)
+        {
+            return;
+        }
+        thread::sleep(Duration::from_millis(2));
+    }
+    panic!("never saw a {hunger:?} bite");
+}
+
+#[test]
+fn bites_follow_the_schedule() {
+    let _victim = vec![0u8; BLOCK];
+    for phase in [Hunger::Hungry, Hunger::Starving, Hunger::Devouring] {
+        wait_for_phase(phase);
+    }
+
+    thread::sleep(Duration::from_millis(500));
+    assert_eq!(craturn::effective_hunger(), Hunger::Devouring);
+    let mask = craturn::BiteConfig::from_hunger(Hunger::Devouring).mask;
+    assert_eq!(craturn::bite_now().map(|bite| bite.mask), Some(mask));
+}
@@ -0,0 +1,49 @@
+//! Many threads taking registry slots at the same time, round after round: every live block is
+//! tracked exactly once, so no slot was handed out to two of them.
+
+use std::collections::HashMap;
+use std::sync::{Barrier, Mutex};
+use std::thread;
+
+const BLOCK: usize = 1_111;
+const THREADS: usize = 24;
+const ROUNDS: usize = 50;
+const LIVE: usize = 64;
+
+// Nothing but the blocks below is tracked, and nothing is ever bitten.
+craturn::awaken!(Full, min_size = BLOCK, max_size = BLOCK);
+
+#[test]
+fn no_slot_is_handed_out_twice() {
+    let barrier = Barrier::new(THREADS);
+    let alive = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..THREADS {
+            scope.spawn(|| {
+                for _ in 0..ROUNDS {
+                    let blocks: Vec<Vec<u8>> = (0..LIVE).map(|_| vec![0; BLOCK]).collect();
+                    let addrs = blocks.iter().map(|block| block.as_ptr() as usize);
+                    alive.lock().unwrap().extend(addrs);
+                    if barrier.wait().is_leader() {
+                        let mut alive = alive.lock().unwrap();
+                        let mut seen = HashMap::new();
+                        for (addr, size) in craturn::tracked() {
+                            assert_eq!(size, BLOCK);
+                            *seen.entry(addr).or_insert(0) += 1;
+                        }
+                        assert_eq!(seen.len(), THREADS * LIVE);
+                        for addr in alive.drain(..) {
+                            assert_eq!(seen.get(&addr), Some(&1), "block {addr:#x}");
+                        }
+                    }
+                    barrier.wait();
+                    drop(blocks);
+                }
+            });
+        }
+    });
+
+    let stats = craturn::stats();
+    assert_eq!(stats.allocations_missed, 0, "{stats:?}");
+    assert_eq!(stats.slots_in_use, 0, "{stats:?}");
+}
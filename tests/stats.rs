@@ -0,0 +1,27 @@
+//! A bite shows up in the stats: counted, with the words it flipped and the block it hit.
+
+// Only the test's own block is tracked, so it is the only thing a bite can land on.
+const BLOCK: usize = 3333;
+
+craturn::awaken!(Hungry, manual = true, min_size = BLOCK, max_size = BLOCK);
+
+#[test]
+fn a_bite_is_counted() {
+    let victim = vec![0u8; BLOCK];
+    let before = craturn::stats();
+    assert_eq!(before.total_bites, 0);
+    assert_eq!(before.active_tracked, 1);
+
+    let event = craturn::bite_now().expect("nothing to bite");
+    assert_eq!(event.addr, victim.as_ptr() as usize);
+    assert_ne!(event.before, event.after);
+    let after = craturn::stats();
+    assert!(after.total_bites >= 1);
+    assert!(after.words_flipped >= 1);
+    assert!(after.allocations_hit >= 1);
+    assert_eq!(after.active_tracked, 1);
+    assert!(
+        victim.iter().any(|&b| b != 0),
+        "the block came out untouched"
+    );
+}
@@ -0,0 +1,55 @@
+//! Rewriting the thread filter over and over never lets a bite through to a thread excluded all
+//! along.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+const BLOCK: usize = 1_919;
+
+// Dry, so the bites can be looked at without breaking anything.
+craturn::awaken!(
+    Hungry,
+    manual = true,
+    min_size = BLOCK,
+    max_size = BLOCK,
+    dry_run = true
+);
+
+/// Stops the rewriter however the biter ends, so a failed assertion can't hang the test.
+struct Done<'a>(&'a AtomicBool);
+
+impl Drop for Done<'_> {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn excluded_threads_stay_excluded_while_the_filter_changes() {
+    let excluded = vec![0u8; BLOCK];
+    let excluded_addr = excluded.as_ptr() as usize;
+    let me = craturn::current_thread_id();
+    let (other, _bitten) = thread::spawn(|| (craturn::current_thread_id(), vec![0u8; BLOCK]))
+        .join()
+        .unwrap();
+    craturn::exclude_threads(&[me]);
+
+    let done = AtomicBool::new(false);
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            while !done.load(Ordering::Relaxed) {
+                craturn::exclude_threads(&[me, other + 1000]);
+                craturn::exclude_threads(&[me]);
+            }
+        });
+        let _stop = Done(&done);
+        let mut bites = 0;
+        for _ in 0..50_000 {
+            if let Some(bite) = craturn::bite_now() {
+                assert_ne!(bite.addr, excluded_addr, "bit an excluded thread");
+                bites += 1;
+            }
+        }
+        assert!(bites > 0);
+    });
+}
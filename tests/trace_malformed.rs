@@ -0,0 +1,75 @@
+//! `replay_trace` turns away anything but a whole trace, without panicking on any input.
+
+use craturn::TraceError;
+
+// Nothing is ever bitten, whatever gets replayed.
+craturn::awaken!(Full, manual = true);
+
+/// A step as `export_trace` writes it, with every number below 128, so it fits in one byte.
+fn step(tick: u8, serial: u8, offset: u8, mask: u8, width: u8, kind: u8) -> [u8; 6] {
+    [tick, serial, offset, mask, width, kind]
+}
+
+#[test]
+fn bad_traces_are_refused() {
+    for bytes in [&b""[..], b"CRT", b"CRT2", b"\0CRT1"] {
+        assert_eq!(craturn::replay_trace(bytes), Err(TraceError::NotATrace));
+    }
+
+    let mut trace = b"CRT1".to_vec();
+    trace.extend(step(1, 0, 8, 0x01, 8, 0));
+    trace.extend(step(2, 3, 0, 0x7F, 1, 6));
+    for len in 4..trace.len() {
+        let expected = match len {
+            4 => Ok(0),
+            10 => Ok(1),
+            _ => Err(TraceError::Malformed),
+        };
+        assert_eq!(
+            craturn::replay_trace(&trace[..len]),
+            expected,
+            "{len} bytes"
+        );
+    }
+    assert_eq!(craturn::replay_trace(&trace), Ok(2));
+
+    let malformed = [
+        // A width no word has.
+        [&b"CRT1"[..], &step(1, 0, 0, 1, 3, 0)].concat(),
+        // A kind there is none of.
+        [&b"CRT1"[..], &step(1, 0, 0, 1, 8, 200)].concat(),
+        // A number that never ends.
+        [&b"CRT1"[..], &[0x80; 11], &step(0, 0, 0, 1, 8, 0)[1..]].concat(),
+        // Ticks adding up past the end of time.
+        [
+            &b"CRT1"[..],
+            &[
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0, 0, 1, 8, 0,
+            ],
+            &[
+                0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0, 0, 1, 8, 0,
+            ],
+        ]
+        .concat(),
+    ];
+    for bytes in malformed {
+        assert_eq!(
+            craturn::replay_trace(&bytes),
+            Err(TraceError::Malformed),
+            "{bytes:?}"
+        );
+    }
+
+    // Whatever follows the magic, the answer is a count or an error.
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    for len in 0..10_000 {
+        let mut bytes = b"CRT1".to_vec();
+        bytes.extend((0..len % 64).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        }));
+        let _ = craturn::replay_trace(&bytes);
+    }
+}
@@ -0,0 +1,112 @@
+//! A trace exported from one run takes the very same bites when replayed in the next, and bites
+//! whose allocation the replaying run never makes are counted as divergences. Each run is a child
+//! process of this test, since a trace must be replayed before the allocations it bit are made.
+
+use std::path::Path;
+use std::process::Command;
+
+use craturn::{Allocator, Hunger};
+
+const BLOCK: usize = 3_001;
+const BLOCKS: usize = 8;
+const TICKS: usize = 20;
+
+#[global_allocator]
+static A: Allocator = Allocator::builder()
+    .hunger(Hunger::Starving)
+    .manual(true)
+    .seed(7)
+    .trace(true)
+    .min_size(BLOCK)
+    .max_size(BLOCK)
+    .build();
+
+/// One run, printing the bites it took by block, offset and value.
+fn child(mode: &str, path: &Path) {
+    if mode != "record" {
+        craturn::exempt_current_thread();
+        let trace = std::fs::read(path).unwrap();
+        craturn::unexempt_current_thread();
+        craturn::replay_trace(&trace).unwrap();
+    }
+    let mut blocks: Vec<Vec<u8>> = (0..BLOCKS).map(|i| vec![i as u8 + 1; BLOCK]).collect();
+    if mode == "diverge" {
+        blocks[0] = Vec::new();
+        blocks[1] = Vec::new();
+    }
+    // Replays stay quiet once the trace is done.
+    let ticks = if mode == "record" { TICKS } else { TICKS * 2 };
+    for _ in 0..ticks {
+        A.do_one_bite();
+    }
+    // Off the line the test harness started.
+    println!();
+    for bite in craturn::recent_bites(1024) {
+        let block = blocks
+            .iter()
+            .position(|b| b.as_ptr() as usize == bite.addr)
+            .unwrap();
+        println!(
+            "bite {block} {} {:#x} {:#x}",
+            bite.offset, bite.before, bite.after
+        );
+    }
+    println!("divergences {}", craturn::stats().replay_divergences);
+    if mode == "record" {
+        std::fs::write(path, craturn::export_trace()).unwrap();
+    }
+}
+
+/// What the child run in `mode` printed.
+fn run(mode: &str, path: &Path) -> Vec<String> {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "replays_the_same_bites",
+            "--nocapture",
+            "--test-threads=1",
+        ])
+        .env("TRACE_REPLAY_MODE", mode)
+        .env("TRACE_REPLAY_PATH", path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{mode}: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("bite ") || line.starts_with("divergences "))
+        .map(str::to_owned)
+        .collect()
+}
+
+#[test]
+fn replays_the_same_bites() {
+    if let Ok(mode) = std::env::var("TRACE_REPLAY_MODE") {
+        child(
+            &mode,
+            Path::new(&std::env::var_os("TRACE_REPLAY_PATH").unwrap()),
+        );
+        return;
+    }
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("trace_replay.bin");
+
+    let recorded = run("record", &path);
+    assert!(recorded.len() > TICKS, "{recorded:?}");
+    assert_eq!(recorded.last().unwrap(), "divergences 0");
+    assert_eq!(run("replay", &path), recorded);
+
+    let lost = |line: &&String| line.starts_with("bite 0 ") || line.starts_with("bite 1 ");
+    let diverged = run("diverge", &path);
+    let mut expected: Vec<_> = recorded
+        .iter()
+        .filter(|line| !lost(line))
+        .cloned()
+        .collect();
+    let lost = recorded.iter().filter(lost).count();
+    assert!(lost > 0, "{recorded:?}");
+    *expected.last_mut().unwrap() = format!("divergences {lost}");
+    assert_eq!(diverged, expected);
+}
@@ -0,0 +1,50 @@
+//! A `Tripwire` trips at the first use of a bitten value, panicking or calling the `on_trip`
+//! handler, and takes changes made through `DerefMut` in without tripping.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use craturn::{Tripped, Tripwire};
+
+const BLOCK: usize = 2_121;
+
+// Only the wired vector's buffer is tracked, so every bite lands in it.
+craturn::awaken!(Hungry, manual = true, min_size = BLOCK, max_size = BLOCK);
+
+static TRIPS: AtomicUsize = AtomicUsize::new(0);
+
+fn count_trip(tripped: &Tripped) {
+    assert!(tripped.type_name.contains("Vec<u8>"), "{tripped}");
+    assert_ne!(tripped.expected, tripped.found);
+    TRIPS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[test]
+fn trips_on_bites_but_not_on_changes() {
+    let mut wired = Tripwire::new(vec![0x11u8; BLOCK]);
+    wired[0] = 0x22;
+    wired[BLOCK - 1] = 0x33;
+    assert_eq!(wired.verify(), Ok(()));
+    assert_eq!(wired[0], 0x22);
+
+    let addr = wired.as_ptr() as usize;
+    let bite = craturn::bite_now().expect("nothing to bite");
+    assert_eq!(bite.addr, addr);
+    let panic = panic::catch_unwind(AssertUnwindSafe(|| wired.len())).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains("was bitten"), "{message}");
+    // Reported once, then trusted as it is.
+    assert_eq!(wired.verify(), Ok(()));
+
+    craturn::on_trip(count_trip);
+    craturn::bite_now().expect("nothing to bite");
+    assert_eq!(wired.len(), BLOCK);
+    assert_eq!(TRIPS.load(Ordering::Relaxed), 1);
+
+    // A bite before a change is still caught by the change.
+    craturn::bite_now().expect("nothing to bite");
+    wired[1] = 0x44;
+    assert_eq!(TRIPS.load(Ordering::Relaxed), 2);
+    assert_eq!(wired.into_inner()[1], 0x44);
+    assert_eq!(TRIPS.load(Ordering::Relaxed), 2);
+}